@@ -0,0 +1,123 @@
+use anyhow::*;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+/// Known answers for the real puzzle inputs, stored as TOML keyed by day:
+///
+/// ```toml
+/// [01]
+/// part1 = "1234"
+/// part2 = "5678"
+/// ```
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Answers {
+    days: BTreeMap<String, DayAnswers>,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize)]
+struct DayAnswers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part2: Option<String>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Verdict {
+    Correct,
+    Wrong { expected: String },
+    Unknown,
+}
+
+impl DayAnswers {
+    fn part_mut(&mut self, part: u8) -> &mut Option<String> {
+        match part {
+            1 => &mut self.part1,
+            2 => &mut self.part2,
+            _ => panic!("invalid part {}", part),
+        }
+    }
+}
+
+impl Answers {
+    /// Loads the answers file, treating a missing file as empty.
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Cannot read answers from {}", path.display()))?;
+        toml::from_str(&content).with_context(|| format!("Invalid answers file {}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        fs::write(path, toml::to_string(self)?)
+            .with_context(|| format!("Cannot write answers to {}", path.display()))
+    }
+
+    pub fn get(&self, day: &str, part: u8) -> Option<&str> {
+        let answers = self.days.get(day)?;
+        match part {
+            1 => answers.part1.as_deref(),
+            2 => answers.part2.as_deref(),
+            _ => None,
+        }
+    }
+
+    pub fn record(&mut self, day: &str, part: u8, answer: &str) {
+        let answers = self.days.entry(day.to_string()).or_default();
+        *answers.part_mut(part) = Some(answer.to_string());
+    }
+
+    pub fn check(&self, day: &str, part: u8, answer: &str) -> Verdict {
+        match self.get(day, part) {
+            None => Verdict::Unknown,
+            Some(expected) if expected == answer => Verdict::Correct,
+            Some(expected) => Verdict::Wrong {
+                expected: expected.to_string(),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn record_and_check() {
+        let mut answers = Answers::default();
+        answers.record("07", 1, "3749");
+
+        assert_eq!(Verdict::Correct, answers.check("07", 1, "3749"));
+        assert_eq!(
+            Verdict::Wrong {
+                expected: "3749".to_string()
+            },
+            answers.check("07", 1, "42")
+        );
+        assert_eq!(Verdict::Unknown, answers.check("07", 2, "11387"));
+    }
+
+    #[test]
+    fn toml_roundtrip() -> Result<()> {
+        let mut answers = Answers::default();
+        answers.record("01", 1, "11");
+        answers.record("01", 2, "31");
+        answers.record("25", 1, "3");
+
+        let serialized = toml::to_string(&answers)?;
+        assert_eq!(
+            "[01]\npart1 = \"11\"\npart2 = \"31\"\n\n[25]\npart1 = \"3\"\n",
+            serialized
+        );
+
+        let parsed: Answers = toml::from_str(&serialized)?;
+        assert_eq!(Some("31"), parsed.get("01", 2));
+        assert_eq!(None, parsed.get("25", 2));
+        Ok(())
+    }
+}
@@ -0,0 +1,304 @@
+use adv_code_2024::answers::{Answers, Verdict};
+use adv_code_2024::input::{InputSource, INPUT_DIR_VAR};
+use adv_code_2024::timing::{self, Report, Timing};
+use adv_code_2024::*;
+use anyhow::*;
+use clap::{Parser, Subcommand};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+/// Runs the Advent of Code 2024 solutions.
+#[derive(Parser)]
+#[command(
+    name = "aoc",
+    args_conflicts_with_subcommands = true,
+    subcommand_negates_reqs = true
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// Days to run, e.g. `--day 1 --day 5` or `--day 1,5`
+    #[arg(short, long, value_delimiter = ',', required_unless_present = "all")]
+    day: Vec<u8>,
+
+    /// Run every implemented day
+    #[arg(short, long, conflicts_with = "day")]
+    all: bool,
+
+    /// Only run the given part
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Puzzle input to use instead of `NN.txt` in the input directory, `-` for stdin (single
+    /// day only)
+    #[arg(short, long)]
+    input: Option<PathBuf>,
+
+    /// Directory with the `NN.txt` puzzle inputs [default: `input` in the crate root]
+    #[arg(long, env = INPUT_DIR_VAR)]
+    input_dir: Option<PathBuf>,
+
+    /// Override a puzzle parameter of the day, e.g. `--param width=11` (single day only)
+    #[arg(long = "param", value_name = "NAME=VALUE", value_parser = parse_param)]
+    params: Vec<(String, String)>,
+
+    /// Write the input of the day in another format instead of solving it, e.g. `--export dot`
+    /// (single day only)
+    #[arg(long, value_name = "FORMAT", conflicts_with_all = ["part", "record", "check"])]
+    export: Option<String>,
+
+    /// How much to print besides the answers
+    #[arg(short, long, value_enum, default_value_t = Verbosity::Info)]
+    verbosity: Verbosity,
+
+    /// File with the known answers for the real inputs [default: `answers.toml` in the crate root]
+    #[arg(long)]
+    answers: Option<PathBuf>,
+
+    /// Store the computed answers in the answers file
+    #[arg(long, conflicts_with = "check")]
+    record: bool,
+
+    /// Compare the computed answers with the answers file and fail on mismatches
+    #[arg(long)]
+    check: bool,
+
+    /// Write the parse and solve times of the run as JSON
+    #[arg(long)]
+    report_json: Option<PathBuf>,
+
+    /// Write the parse and solve times of the run as a Markdown table
+    #[arg(long)]
+    report_md: Option<PathBuf>,
+
+    /// JSON timing report of an earlier run to compare against
+    #[arg(long)]
+    baseline: Option<PathBuf>,
+
+    /// Slowdown against the baseline, in percent, that counts as a regression
+    #[arg(long, default_value_t = 20.0, requires = "baseline")]
+    threshold: f64,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Create the source file and an empty input for a new day from the template
+    NewDay {
+        /// Day of the puzzle
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+    /// List the puzzle parameters of a day and their defaults, and its export formats
+    Params {
+        /// Day of the puzzle
+        day: u8,
+    },
+}
+
+fn run_part(part: u8, run: impl FnOnce() -> Result<String>) -> Result<(u8, String, Timing)> {
+    let (result, timing) = timing::measure(run);
+    let result = result?;
+    info!(
+        "Took {:?} (parse {:?}, solve {:?})",
+        timing.parse + timing.solve,
+        timing.parse,
+        timing.solve
+    );
+    println!("Result = {}", result);
+    Ok((part, result, timing))
+}
+
+fn read_params(solution: &dyn Solution, cli: &Cli) -> Result<Params> {
+    let overrides: Vec<_> = cli
+        .params
+        .iter()
+        .map(|(name, value)| (name.as_str(), value.as_str()))
+        .collect();
+    Params::new(solution.params(), &overrides)
+        .with_context(|| format!("Invalid parameters for day {}", solution.day()))
+}
+
+fn read_input(solution: &dyn Solution, cli: &Cli) -> Result<(Vec<u8>, Params)> {
+    let input_dir = cli
+        .input_dir
+        .clone()
+        .unwrap_or_else(|| Path::new(env!("CARGO_MANIFEST_DIR")).join("input"));
+    let params = read_params(solution, cli)?;
+    let input = InputSource::resolve(solution.day(), cli.input.as_deref(), &input_dir).read()?;
+    Ok((input, params))
+}
+
+fn export_day(solution: &dyn Solution, cli: &Cli, name: &str) -> Result<()> {
+    let Some(export) = solution.exports().iter().find(|e| e.name == name) else {
+        bail!(
+            "Day {} has no export `{}` (known: {})",
+            solution.day(),
+            name,
+            solution
+                .exports()
+                .iter()
+                .map(|e| e.name)
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+    };
+    let (input, params) = if export.reads_input {
+        read_input(solution, cli)?
+    } else {
+        if cli.input.is_some() {
+            bail!("--export {} does not read an input", name);
+        }
+        (vec![], read_params(solution, cli)?)
+    };
+    print!("{}", solution.export(&mut input.as_slice(), name, &params)?);
+    Ok(())
+}
+
+fn run_day(solution: &dyn Solution, cli: &Cli) -> Result<Vec<(u8, String, Timing)>> {
+    start_day(solution.day());
+
+    let (input, params) = read_input(solution, cli)?;
+
+    let mut results = vec![];
+
+    if cli.part.is_none_or(|p| p == 1) {
+        info!("=== Part 1 ===");
+        results.push(run_part(1, || {
+            solution.part1(&mut input.as_slice(), &params)
+        })?);
+    }
+
+    if solution.has_part2() && cli.part.is_none_or(|p| p == 2) {
+        info!("\n=== Part 2 ===");
+        results.push(run_part(2, || {
+            solution.part2(&mut input.as_slice(), &params)
+        })?);
+    }
+
+    Ok(results)
+}
+
+fn main() -> Result<ExitCode> {
+    let cli = Cli::parse();
+    set_verbosity(cli.verbosity);
+
+    match cli.command {
+        Some(Command::NewDay { day }) => {
+            for path in scaffold::new_day(Path::new(env!("CARGO_MANIFEST_DIR")), day)? {
+                println!("Wrote {}", path.display());
+            }
+            return Ok(ExitCode::SUCCESS);
+        }
+        Some(Command::Params { day }) => {
+            let solution =
+                days::get(day).ok_or_else(|| anyhow!("Day {} is not implemented", day))?;
+            if solution.params().is_empty() {
+                println!("Day {} has no parameters", solution.day());
+            }
+            for param in solution.params() {
+                println!(
+                    "{}={}\t{}\t{}",
+                    param.name, param.default, param.kind, param.help
+                );
+            }
+            for export in solution.exports() {
+                println!("--export {}\t{}", export.name, export.help);
+            }
+            return Ok(ExitCode::SUCCESS);
+        }
+        None => {}
+    }
+
+    let solutions = if cli.all {
+        days::ALL.to_vec()
+    } else {
+        cli.day
+            .iter()
+            .map(|&d| days::get(d).ok_or_else(|| anyhow!("Day {} is not implemented", d)))
+            .collect::<Result<Vec<_>>>()?
+    };
+
+    if cli.input.is_some() && solutions.len() != 1 {
+        bail!("--input can only be used when running a single day");
+    }
+    if !cli.params.is_empty() && solutions.len() != 1 {
+        bail!("--param can only be used when running a single day");
+    }
+    if (cli.input.is_some() || !cli.params.is_empty()) && (cli.record || cli.check) {
+        bail!("--record and --check only apply to the default puzzle inputs and parameters");
+    }
+    if let Some(name) = &cli.export {
+        if solutions.len() != 1 {
+            bail!("--export can only be used when running a single day");
+        }
+        export_day(solutions[0], &cli, name)?;
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    let answers_path = cli
+        .answers
+        .clone()
+        .unwrap_or_else(|| Path::new(env!("CARGO_MANIFEST_DIR")).join("answers.toml"));
+    let mut answers = Answers::load(&answers_path)?;
+    let mut mismatches = vec![];
+    let mut report = Report::default();
+
+    for (i, solution) in solutions.into_iter().enumerate() {
+        if i > 0 {
+            info!();
+        }
+        let day = solution.day();
+        for (part, result, timing) in run_day(solution, &cli)? {
+            report.add(day, part, timing);
+            if cli.record {
+                answers.record(day, part, &result);
+            } else if cli.check {
+                match answers.check(day, part, &result) {
+                    Verdict::Correct => info!("Answer is correct"),
+                    Verdict::Unknown => info!("No known answer"),
+                    Verdict::Wrong { expected } => {
+                        println!("MISMATCH: expected {}", expected);
+                        mismatches.push(format!("day {} part {}", day, part));
+                    }
+                }
+            }
+        }
+    }
+
+    if cli.record {
+        answers.save(&answers_path)?;
+        info!("\nAnswers recorded in {}", answers_path.display());
+    }
+
+    if let Some(path) = &cli.report_json {
+        report.save_json(path)?;
+    }
+    if let Some(path) = &cli.report_md {
+        report.save_markdown(path)?;
+    }
+
+    let regressions = match &cli.baseline {
+        Some(path) => report.regressions(&Report::load(path)?, cli.threshold),
+        None => vec![],
+    };
+    if !regressions.is_empty() {
+        eprintln!(
+            "\nSlower than the baseline by more than {}%:",
+            cli.threshold
+        );
+        for regression in &regressions {
+            eprintln!("  {}", regression);
+        }
+    }
+
+    if !mismatches.is_empty() {
+        eprintln!("\nWrong answers for {}", mismatches.join(", "));
+    }
+
+    if !mismatches.is_empty() || !regressions.is_empty() {
+        return Ok(ExitCode::FAILURE);
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
@@ -0,0 +1,88 @@
+use crate::{examples, lines, timing, trace, Params, Solution};
+use anyhow::*;
+use std::io::BufRead;
+use std::iter::zip;
+
+const DAY: &str = "01";
+
+const TEST: &str = "\
+3   4
+4   3
+2   5
+1   3
+3   9
+3   3
+";
+
+examples! {
+    Day01;
+    example: TEST => { part1: "11", part2: "31" },
+}
+
+struct Input {
+    first_col: Vec<i64>,
+    second_col: Vec<i64>,
+}
+
+fn parse_input<R: BufRead>(reader: R) -> Result<Input> {
+    let mut input = Input {
+        first_col: vec![],
+        second_col: vec![],
+    };
+
+    for line in lines(DAY, reader) {
+        let line = line?;
+        let parts = line.split_once(" ")?;
+        trace!("{:?}", parts);
+        let num1 = line.parse::<i64>(parts.0)?;
+        let num2 = line.parse::<i64>(parts.1)?;
+        input.first_col.push(num1);
+        input.second_col.push(num2);
+    }
+
+    Ok(input)
+}
+
+fn part1<R: BufRead>(reader: R) -> Result<i64> {
+    let mut input = timing::parse(|| parse_input(reader))?;
+    input.first_col.sort();
+    input.second_col.sort();
+
+    let answer = zip(input.first_col, input.second_col)
+        .map(|(a, b)| (b - a).abs())
+        .sum();
+
+    Ok(answer)
+}
+
+fn part2<R: BufRead>(reader: R) -> Result<i64> {
+    let input = timing::parse(|| parse_input(reader))?;
+    let mut answer = 0i64;
+    for num in input.first_col {
+        let count: i64 = input
+            .second_col
+            .iter()
+            .filter(|a| num == **a)
+            .map(|_| 1)
+            .sum();
+        answer += num * count;
+    }
+
+    Ok(answer)
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    fn day(&self) -> &'static str {
+        DAY
+    }
+
+    fn part1(&self, reader: &mut dyn BufRead, _params: &Params) -> Result<String> {
+        Ok(part1(reader)?.to_string())
+    }
+
+    fn part2(&self, reader: &mut dyn BufRead, _params: &Params) -> Result<String> {
+        Ok(part2(reader)?.to_string())
+    }
+}
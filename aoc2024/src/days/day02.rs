@@ -0,0 +1,103 @@
+use crate::{examples, timing, Params, Solution};
+use anyhow::*;
+use std::io::BufRead;
+
+const DAY: &str = "02";
+
+const TEST: &str = "\
+7 6 4 2 1
+1 2 7 8 9
+9 7 6 2 1
+1 3 2 4 5
+8 6 4 4 1
+1 3 6 7 9
+";
+
+examples! {
+    Day02;
+    example: TEST => { part1: "2", part2: "4" },
+}
+
+struct Input {
+    lines: Vec<Vec<i64>>,
+}
+
+fn parse_input<R: BufRead>(reader: R) -> Result<Input> {
+    let mut lines = vec![];
+
+    for line in crate::lines(DAY, reader) {
+        let line = line?;
+        let numbers = line
+            .text
+            .split(" ")
+            .map(|s| line.parse::<i64>(s))
+            .collect::<Result<Vec<_>>>()?;
+        lines.push(numbers);
+    }
+
+    let input = Input { lines };
+
+    Ok(input)
+}
+
+fn part1<R: BufRead>(reader: R) -> Result<usize> {
+    let input = timing::parse(|| parse_input(reader))?;
+
+    let mut result = 0;
+
+    for line in input.lines {
+        let sliding_window = line.windows(2);
+        let differences: Vec<_> = sliding_window.map(|w| w[1] - w[0]).collect();
+        //println!("{:#?}", differences);
+        let all_up = differences.iter().all(|x| 0 < *x && *x < 4);
+        let all_down = differences.iter().all(|x| -4 < *x && *x < 0);
+
+        result += (all_up || all_down) as usize;
+    }
+
+    Ok(result)
+}
+
+fn part2<R: BufRead>(reader: R) -> Result<usize> {
+    let input = timing::parse(|| parse_input(reader))?;
+
+    let mut result = 0;
+
+    for line in input.lines {
+        // hint: removing the first or last element does never break a safe list, so we
+        // do not need a special case for lists safe without removing an element.
+        for i in 0..line.len() {
+            let mut variation = line.clone();
+            variation.remove(i);
+
+            let sliding_window = variation.windows(2);
+            let differences: Vec<_> = sliding_window.map(|w| w[1] - w[0]).collect();
+            //println!("{:#?}", differences);
+            let all_up = differences.iter().all(|x| 0 < *x && *x < 4);
+            let all_down = differences.iter().all(|x| -4 < *x && *x < 0);
+
+            if all_up || all_down {
+                result += 1;
+                break;
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    fn day(&self) -> &'static str {
+        DAY
+    }
+
+    fn part1(&self, reader: &mut dyn BufRead, _params: &Params) -> Result<String> {
+        Ok(part1(reader)?.to_string())
+    }
+
+    fn part2(&self, reader: &mut dyn BufRead, _params: &Params) -> Result<String> {
+        Ok(part2(reader)?.to_string())
+    }
+}
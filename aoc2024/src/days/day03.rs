@@ -0,0 +1,109 @@
+use self::Instr::{DO, DONT};
+use crate::{examples, lines, Params, Solution};
+use anyhow::*;
+use regex::Regex;
+use std::io::BufRead;
+
+const DAY: &str = "03";
+
+const TEST: &str = "\
+xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))
+";
+
+const TEST2: &str = "\
+xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))
+";
+
+examples! {
+    Day03;
+    example1: TEST => { part1: "161" },
+    example2: TEST2 => { part2: "48" },
+}
+
+#[allow(clippy::upper_case_acronyms)]
+enum Instr {
+    DO,
+    DONT,
+    MUL(i64, i64),
+}
+
+struct InputMatch {
+    pos: usize,
+    instr: Instr,
+}
+
+fn part1<R: BufRead>(reader: R) -> Result<i64> {
+    let mut result = 0;
+    let regex = Regex::new(r"mul\((\d{1,3}),(\d{1,3})\)")?;
+    for line in lines(DAY, reader) {
+        let line = line?;
+        for cap in regex.captures_iter(&line.text) {
+            let a: i64 = line.parse(&cap[1])?;
+            let b: i64 = line.parse(&cap[2])?;
+            result += a * b;
+        }
+    }
+
+    Ok(result)
+}
+
+fn part2<R: BufRead>(reader: R) -> Result<i64> {
+    let do_regex = Regex::new(r"do\(\)")?;
+    let dont_regex = Regex::new(r"don't\(\)")?;
+    let mul_regex = Regex::new(r"mul\((\d{1,3}),(\d{1,3})\)")?;
+
+    let mut result = 0;
+    let mut mode = 1;
+
+    for line in lines(DAY, reader) {
+        let line = line?;
+        let text = line.text.as_str();
+        let mut linematches = vec![];
+        do_regex.find_iter(text).for_each(|x| {
+            linematches.push(InputMatch {
+                pos: x.start(),
+                instr: DO,
+            })
+        });
+        dont_regex.find_iter(text).for_each(|x| {
+            linematches.push(InputMatch {
+                pos: x.start(),
+                instr: DONT,
+            })
+        });
+        for x in mul_regex.captures_iter(text) {
+            linematches.push(InputMatch {
+                pos: x.get(0).map_or(0, |whole| whole.start()),
+                instr: Instr::MUL(line.parse(&x[1])?, line.parse(&x[2])?),
+            });
+        }
+
+        linematches.sort_by_key(|a| a.pos);
+
+        for v in linematches {
+            match v.instr {
+                DO => mode = 1,
+                DONT => mode = 0,
+                Instr::MUL(a, b) => result += a * b * mode,
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    fn day(&self) -> &'static str {
+        DAY
+    }
+
+    fn part1(&self, reader: &mut dyn BufRead, _params: &Params) -> Result<String> {
+        Ok(part1(reader)?.to_string())
+    }
+
+    fn part2(&self, reader: &mut dyn BufRead, _params: &Params) -> Result<String> {
+        Ok(part2(reader)?.to_string())
+    }
+}
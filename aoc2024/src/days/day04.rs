@@ -0,0 +1,83 @@
+use crate::grid::Grid;
+use crate::point::Point;
+use crate::{examples, timing, Params, Solution};
+use anyhow::*;
+use std::io::BufRead;
+
+const DAY: &str = "04";
+
+const TEST: &str = "\
+MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX
+";
+
+examples! {
+    Day04;
+    example: TEST => { part1: "18", part2: "9" },
+}
+
+const SEARCH_EXPR_1: [char; 4] = ['X', 'M', 'A', 'S'];
+
+fn read_input<R: BufRead>(reader: R) -> Result<Grid<char>> {
+    Grid::read(DAY, reader, "XMAS")
+}
+
+fn part1<R: BufRead>(reader: R) -> Result<u32> {
+    let input = timing::parse(|| read_input(reader))?;
+    let mut result = 0;
+    for p in input.points() {
+        for xd in -1..2 {
+            for yd in -1..2 {
+                let found = SEARCH_EXPR_1.iter().enumerate().all(|(d, c)| {
+                    let d = d as i64;
+                    input.get(Point::new(p.row + xd * d, p.col + yd * d)) == Some(c)
+                });
+                result += found as u32;
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+fn part2<R: BufRead>(reader: R) -> Result<u32> {
+    let input = timing::parse(|| read_input(reader))?;
+    let at = |p: Point, xd, yd| {
+        *input
+            .get(Point::new(p.row + xd, p.col + yd))
+            .unwrap_or(&'.')
+    };
+    let mut result = 0;
+    for p in input.points() {
+        let w1 = String::from_iter([at(p, -1, -1), at(p, 0, 0), at(p, 1, 1)]);
+        let w2 = String::from_iter([at(p, 1, -1), at(p, 0, 0), at(p, -1, 1)]);
+
+        result += ((w1 == "MAS" || w1 == "SAM") && (w2 == "MAS" || w2 == "SAM")) as u32;
+    }
+
+    Ok(result)
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    fn day(&self) -> &'static str {
+        DAY
+    }
+
+    fn part1(&self, reader: &mut dyn BufRead, _params: &Params) -> Result<String> {
+        Ok(part1(reader)?.to_string())
+    }
+
+    fn part2(&self, reader: &mut dyn BufRead, _params: &Params) -> Result<String> {
+        Ok(part2(reader)?.to_string())
+    }
+}
@@ -0,0 +1,127 @@
+use crate::{examples, lines, timing, Params, Solution};
+use anyhow::*;
+use itertools::Itertools;
+use std::collections::HashSet;
+use std::io::BufRead;
+
+const DAY: &str = "05";
+
+const TEST: &str = "\
+47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47
+";
+
+examples! {
+    Day05;
+    example: TEST => { part1: "143", part2: "123" },
+}
+
+type Rules = HashSet<(i32, i32)>;
+
+fn read_input<R: BufRead>(reader: R) -> Result<(Rules, Vec<Vec<i32>>)> {
+    let mut rules = HashSet::new();
+    let mut checks = vec![];
+    for line in lines(DAY, reader) {
+        let line = line?;
+        if line.text.contains("|") {
+            let (before, after) = line.split_once("|")?;
+            rules.insert((line.parse(before)?, line.parse(after)?));
+        } else if line.text.contains(",") {
+            let pages = line
+                .text
+                .split(",")
+                .map(|page| line.parse(page))
+                .collect::<Result<Vec<i32>>>()?;
+            checks.push(pages);
+        } else if !line.text.is_empty() {
+            return Err(line.error(1, "expected a rule `X|Y` or an update `A,B,C`"));
+        }
+    }
+    Ok((rules, checks))
+}
+
+fn part1<R: BufRead>(reader: R) -> Result<i32> {
+    let (rules, checks) = timing::parse(|| read_input(reader))?;
+
+    let mut result = 0;
+
+    for parts in checks {
+        let ordered = parts
+            .iter()
+            .tuple_combinations()
+            .all(|(&a, &b)| !rules.contains(&(b, a)));
+
+        if ordered {
+            result += parts[parts.len() / 2];
+        }
+    }
+
+    Ok(result)
+}
+
+fn part2<R: BufRead>(reader: R) -> Result<i32> {
+    let (rules, checks) = timing::parse(|| read_input(reader))?;
+
+    let mut res = 0;
+    for current_order in checks {
+        let mut parts: HashSet<_> = HashSet::from_iter(current_order.clone());
+
+        let mut order = vec![];
+        while !parts.is_empty() {
+            // Get number where no other number is in front (topological sort in slow)
+            let a = *(parts
+                .iter()
+                .find(|x| parts.iter().all(|y| !rules.contains(&(*y, **x))))
+                .ok_or_else(|| anyhow!("The rules for update {:?} form a cycle", current_order))?);
+            parts.remove(&a);
+            order.push(a);
+        }
+        if current_order != order {
+            res += order[order.len() / 2];
+        }
+    }
+
+    Ok(res)
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    fn day(&self) -> &'static str {
+        DAY
+    }
+
+    fn part1(&self, reader: &mut dyn BufRead, _params: &Params) -> Result<String> {
+        Ok(part1(reader)?.to_string())
+    }
+
+    fn part2(&self, reader: &mut dyn BufRead, _params: &Params) -> Result<String> {
+        Ok(part2(reader)?.to_string())
+    }
+}
@@ -0,0 +1,336 @@
+use crate::grid::Grid;
+use crate::point::{Direction, Point};
+use crate::{examples, timing, Params, Solution};
+use anyhow::*;
+use itertools::Itertools;
+use rayon::prelude::*;
+use std::collections::HashMap;
+use std::io::BufRead;
+
+const DAY: &str = "06";
+
+const TEST: &str = "\
+....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...
+";
+
+examples! {
+    Day06;
+    example: TEST => { part1: "41", part2: "6" },
+}
+
+/// Where and which way the guard is looking.
+type Guard = (Point, Direction);
+
+/// How a patrol ends.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Outcome {
+    /// The guard left the map with its last step. Turning on the spot counts as a step.
+    Exited { steps: usize },
+    /// The guard walks in circles: the state after `start` steps comes back every `length`
+    /// steps, and no earlier state does.
+    Looped { start: usize, length: usize },
+}
+
+/// The guard's state after one step, `None` once it leaves the map. `extra` is an obstacle that
+/// is not on the map.
+fn step(field: &Grid<char>, extra: Option<Point>, (pos, dir): Guard) -> Option<Guard> {
+    let next = pos.step(dir);
+    let blocked = field.get(next)? == &'#' || extra == Some(next);
+    Some(if blocked {
+        (pos, dir.turn_right())
+    } else {
+        (next, dir)
+    })
+}
+
+/// Every state of the guard on the map, starting at `start` looking up.
+fn patrol(
+    field: &Grid<char>,
+    extra: Option<Point>,
+    start: Point,
+) -> impl Iterator<Item = Guard> + '_ {
+    std::iter::successors(Some((start, Direction::Up)), move |&guard| {
+        step(field, extra, guard)
+    })
+}
+
+/// The obstacles of every row and column in order, so the guard can jump from one turn to the
+/// next. An extra obstacle is passed to the lookups instead of being added, which leaves the table
+/// as it is and lets many candidates share it.
+struct JumpTable {
+    width: i64,
+    height: i64,
+    /// The columns of the obstacles in each row.
+    rows: Vec<Vec<i64>>,
+    /// The rows of the obstacles in each column.
+    columns: Vec<Vec<i64>>,
+}
+
+impl JumpTable {
+    fn new(field: &Grid<char>) -> Self {
+        let mut rows = vec![vec![]; field.height()];
+        let mut columns = vec![vec![]; field.width()];
+        // row by row, so both lists come out sorted
+        for p in field.find_all(&'#') {
+            rows[p.row as usize].push(p.col);
+            columns[p.col as usize].push(p.row);
+        }
+        JumpTable {
+            width: field.width() as i64,
+            height: field.height() as i64,
+            rows,
+            columns,
+        }
+    }
+
+    /// The closest obstacle ahead of the guard, `None` if it has a free way off the map.
+    fn next_obstacle(&self, (pos, dir): Guard, extra: Option<Point>) -> Option<Point> {
+        let (line, x) = if dir.is_horizontal() {
+            (&self.rows[pos.row as usize], pos.col)
+        } else {
+            (&self.columns[pos.col as usize], pos.row)
+        };
+        let before = line.partition_point(|&o| o < x);
+        let found = match dir {
+            Direction::Down | Direction::Right => line.get(before).copied(),
+            Direction::Up | Direction::Left => before.checked_sub(1).map(|i| line[i]),
+        };
+        let found = found.map(|o| {
+            if dir.is_horizontal() {
+                Point::new(pos.row, o)
+            } else {
+                Point::new(o, pos.col)
+            }
+        });
+
+        let extra = extra.filter(|&e| {
+            let distance = e.manhattan_distance(pos);
+            distance > 0 && pos + dir.offset() * distance == e
+        });
+        [found, extra]
+            .into_iter()
+            .flatten()
+            .min_by_key(|o| o.manhattan_distance(pos))
+    }
+
+    /// The steps it takes the guard to walk off the map from `pos`, the last one leaving it.
+    fn steps_to_edge(&self, (pos, dir): Guard) -> usize {
+        (match dir {
+            Direction::Up => pos.row + 1,
+            Direction::Right => self.width - pos.col,
+            Direction::Down => self.height - pos.row,
+            Direction::Left => pos.col + 1,
+        }) as usize
+    }
+
+    /// Jumps from turn to turn until the guard leaves the map or turns into a state it was in
+    /// before. Every cycle contains a turn, so only the states after turns are remembered. Returns
+    /// the steps taken and, for loops, the step at which the repeated state was first reached.
+    fn walk(&self, from: Guard, extra: Option<Point>) -> (usize, Option<usize>) {
+        let mut turns = HashMap::new();
+        let mut guard = from;
+        let mut steps = 0;
+        loop {
+            let Some(obstacle) = self.next_obstacle(guard, extra) else {
+                return (steps + self.steps_to_edge(guard), None);
+            };
+            let (pos, dir) = guard;
+            let stop = obstacle - dir.offset();
+            steps += stop.manhattan_distance(pos) as usize + 1;
+            guard = (stop, dir.turn_right());
+            if let Some(first) = turns.insert(guard, steps) {
+                return (steps, Some(first));
+            }
+        }
+    }
+}
+
+/// Follows the guard from `start` until it leaves the map or comes back to a state it was in.
+fn simulate(field: &Grid<char>, jumps: &JumpTable, extra: Option<Point>, start: Point) -> Outcome {
+    match jumps.walk((start, Direction::Up), extra) {
+        (steps, None) => Outcome::Exited { steps },
+        (steps, Some(first)) => {
+            let length = steps - first;
+            // the cycle starts at the first state that is the same `length` steps later
+            let start = patrol(field, extra, start)
+                .zip(patrol(field, extra, start).skip(length))
+                .position(|(a, b)| a == b)
+                .unwrap();
+            Outcome::Looped { start, length }
+        }
+    }
+}
+
+/// The states of the guard on the map, for patrols that end as well as for loops.
+fn path(field: &Grid<char>, jumps: &JumpTable, start: Point) -> Vec<Guard> {
+    let states = match simulate(field, jumps, None, start) {
+        Outcome::Exited { steps } => steps,
+        Outcome::Looped { start, length } => start + length,
+    };
+    patrol(field, None, start).take(states).collect()
+}
+
+fn read_input<R: BufRead>(reader: R) -> Result<Grid<char>> {
+    let input = Grid::read(DAY, reader, ".#^")?;
+    input.find(&'^').context("Day 06 input has no guard `^`")?;
+    Ok(input)
+}
+
+fn part1<R: BufRead>(reader: R) -> Result<usize> {
+    let input = timing::parse(|| read_input(reader))?;
+
+    let start = input.find(&'^').unwrap();
+    let jumps = JumpTable::new(&input);
+    let mut visited = input.map(|_| false);
+    for (pos, _) in path(&input, &jumps, start) {
+        visited[pos] = true;
+    }
+    let answer = visited.find_all(&true).count();
+    Ok(answer)
+}
+
+fn part2<R: BufRead>(reader: R) -> Result<usize> {
+    let input = timing::parse(|| read_input(reader))?;
+
+    let start = input.find(&'^').unwrap();
+    let jumps = JumpTable::new(&input);
+
+    // an obstacle off the original path does not change it, and the guard only notices one on
+    // the path once it is about to walk into it for the first time
+    let mut visited = input.map(|_| false);
+    visited[start] = true;
+    let mut candidates = vec![];
+    for (before, (pos, _)) in path(&input, &jumps, start).into_iter().tuple_windows() {
+        if !visited[pos] {
+            visited[pos] = true;
+            candidates.push((before, pos));
+        }
+    }
+
+    let result = candidates
+        .par_iter()
+        .filter(|&&(before, obstacle)| jumps.walk(before, Some(obstacle)).1.is_some())
+        .count();
+
+    Ok(result)
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    fn day(&self) -> &'static str {
+        DAY
+    }
+
+    fn part1(&self, reader: &mut dyn BufRead, _params: &Params) -> Result<String> {
+        Ok(part1(reader)?.to_string())
+    }
+
+    fn part2(&self, reader: &mut dyn BufRead, _params: &Params) -> Result<String> {
+        Ok(part2(reader)?.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    /// Remembers every state, to check the cycles found from the turns alone.
+    fn outcome_of_every_state(field: &Grid<char>, extra: Option<Point>, start: Point) -> Outcome {
+        let mut seen = HashMap::new();
+        for (steps, guard) in patrol(field, extra, start).enumerate() {
+            if let Some(first) = seen.insert(guard, steps) {
+                return Outcome::Looped {
+                    start: first,
+                    length: steps - first,
+                };
+            }
+        }
+        Outcome::Exited { steps: seen.len() }
+    }
+
+    #[test]
+    fn example_outcomes() -> Result<()> {
+        let field = read_input(TEST.as_bytes())?;
+        let jumps = JumpTable::new(&field);
+        let start = field.find(&'^').unwrap();
+        assert_eq!(
+            Outcome::Exited { steps: 55 },
+            simulate(&field, &jumps, None, start)
+        );
+        assert!(matches!(
+            simulate(&field, &jumps, Some(Point::new(6, 3)), start),
+            Outcome::Looped { .. }
+        ));
+        // one step up, the guard starts circling around the four cells in the middle
+        let field = read_input(".#..\n...#\n#...\n.^#.\n".as_bytes())?;
+        let start = field.find(&'^').unwrap();
+        assert_eq!(
+            Outcome::Looped {
+                start: 1,
+                length: 8
+            },
+            simulate(&field, &JumpTable::new(&field), None, start)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn jumps_see_the_extra_obstacle() -> Result<()> {
+        let field = read_input(TEST.as_bytes())?;
+        let jumps = JumpTable::new(&field);
+        let guard = (Point::new(6, 4), Direction::Up);
+        assert_eq!(Some(Point::new(0, 4)), jumps.next_obstacle(guard, None));
+        let extra = Some(Point::new(3, 4));
+        assert_eq!(extra, jumps.next_obstacle(guard, extra));
+        // behind the guard, or further away than the obstacle of the map
+        let behind = (Point::new(2, 4), Direction::Up);
+        assert_eq!(Some(Point::new(0, 4)), jumps.next_obstacle(behind, extra));
+        let left = (Point::new(3, 6), Direction::Left);
+        assert_eq!(
+            Some(Point::new(3, 2)),
+            jumps.next_obstacle(left, Some(Point::new(3, 1)))
+        );
+        assert_eq!(
+            None,
+            jumps.next_obstacle((Point::new(9, 7), Direction::Right), None)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn cycles_match_a_search_over_all_states() {
+        let mut rng = StdRng::seed_from_u64(6);
+        for _ in 0..300 {
+            let cells: Vec<Vec<char>> = (0..10)
+                .map(|_| {
+                    (0..10)
+                        .map(|_| if rng.random_bool(0.15) { '#' } else { '.' })
+                        .collect()
+                })
+                .collect();
+            let field = Grid::from_rows(cells).unwrap();
+            let start = Point::new(rng.random_range(0..10), rng.random_range(0..10));
+            let extra = Some(Point::new(rng.random_range(0..10), rng.random_range(0..10)))
+                .filter(|&p| p != start);
+            if field[start] == '#' {
+                continue;
+            }
+            assert_eq!(
+                outcome_of_every_state(&field, extra, start),
+                simulate(&field, &JumpTable::new(&field), extra, start)
+            );
+        }
+    }
+}
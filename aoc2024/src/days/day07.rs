@@ -0,0 +1,407 @@
+use crate::{debug, examples, lines, timing, Param, ParamKind, Params, Solution};
+use anyhow::*;
+use itertools::Itertools;
+use std::fmt::Write;
+use std::io::BufRead;
+
+const DAY: &str = "07";
+
+const PARAMS: &[Param] = &[Param {
+    name: "operators",
+    default: "+,*,||",
+    kind: ParamKind::Text,
+    help: "Operators part 2 tries, out of + * || - ^",
+}];
+
+const TEST: &str = "\
+190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20
+";
+
+examples! {
+    Day07;
+    example: TEST => { part1: "3749", part2: "11387" },
+}
+
+struct Input {
+    expected_answer: u64,
+    numbers: Vec<u64>,
+}
+
+fn read_input<R: BufRead>(reader: R) -> Result<Vec<Input>> {
+    lines(DAY, reader)
+        .map(|line| {
+            let line = line?;
+            let (a, b) = line.split_once(":")?;
+            let expected_answer = line.parse::<u64>(a)?;
+            let numbers = b
+                .split_whitespace()
+                .map(|x| line.parse::<u64>(x))
+                .collect::<Result<Vec<_>>>()?;
+            if numbers.is_empty() {
+                return Err(line.error_at_end("expected numbers after `:`"));
+            }
+
+            Ok(Input {
+                expected_answer,
+                numbers,
+            })
+        })
+        .collect()
+}
+
+/// What an operator can tell about its left operand from the result and the right operand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Inverse {
+    Left(u64),
+    /// No left operand gives the result.
+    Impossible,
+    /// Several left operands could, so the solver has to try the possible ones.
+    Unknown,
+}
+
+/// A binary operator of the equations, which are evaluated left to right.
+trait Operator {
+    fn symbol(&self) -> &'static str;
+
+    /// `left op right`, `None` when that overflows or is undefined.
+    fn apply(&self, left: u64, right: u64) -> Option<u64>;
+
+    /// The left operand that gives `result` with `right`.
+    fn inverse(&self, _result: u64, _right: u64) -> Inverse {
+        Inverse::Unknown
+    }
+}
+
+fn inverse_of(left: Option<u64>) -> Inverse {
+    left.map_or(Inverse::Impossible, Inverse::Left)
+}
+
+struct Add;
+
+impl Operator for Add {
+    fn symbol(&self) -> &'static str {
+        "+"
+    }
+
+    fn apply(&self, left: u64, right: u64) -> Option<u64> {
+        left.checked_add(right)
+    }
+
+    fn inverse(&self, result: u64, right: u64) -> Inverse {
+        inverse_of(result.checked_sub(right))
+    }
+}
+
+struct Mul;
+
+impl Operator for Mul {
+    fn symbol(&self) -> &'static str {
+        "*"
+    }
+
+    fn apply(&self, left: u64, right: u64) -> Option<u64> {
+        left.checked_mul(right)
+    }
+
+    fn inverse(&self, result: u64, right: u64) -> Inverse {
+        match (result, right) {
+            (0, 0) => Inverse::Unknown,
+            (_, 0) => Inverse::Impossible,
+            _ => inverse_of(result.is_multiple_of(right).then(|| result / right)),
+        }
+    }
+}
+
+/// Appends the digits of the right operand to the left one.
+struct Concat;
+
+impl Concat {
+    /// The factor that shifts the left operand past the digits of `right`, `None` when it does
+    /// not fit into 64 bits, so only a left operand of 0 can be shifted.
+    fn shift(right: u64) -> Option<u64> {
+        10u64.checked_pow(right.checked_ilog10().unwrap_or(0) + 1)
+    }
+}
+
+impl Operator for Concat {
+    fn symbol(&self) -> &'static str {
+        "||"
+    }
+
+    fn apply(&self, left: u64, right: u64) -> Option<u64> {
+        match Concat::shift(right) {
+            Some(shift) => left.checked_mul(shift)?.checked_add(right),
+            None => (left == 0).then_some(right),
+        }
+    }
+
+    fn inverse(&self, result: u64, right: u64) -> Inverse {
+        let Some(shift) = Concat::shift(right) else {
+            return inverse_of((result == right).then_some(0));
+        };
+        inverse_of(
+            result
+                .checked_sub(right)
+                .filter(|rest| rest.is_multiple_of(shift))
+                .map(|rest| rest / shift),
+        )
+    }
+}
+
+struct Sub;
+
+impl Operator for Sub {
+    fn symbol(&self) -> &'static str {
+        "-"
+    }
+
+    fn apply(&self, left: u64, right: u64) -> Option<u64> {
+        left.checked_sub(right)
+    }
+
+    fn inverse(&self, result: u64, right: u64) -> Inverse {
+        inverse_of(result.checked_add(right))
+    }
+}
+
+struct Xor;
+
+impl Operator for Xor {
+    fn symbol(&self) -> &'static str {
+        "^"
+    }
+
+    fn apply(&self, left: u64, right: u64) -> Option<u64> {
+        Some(left ^ right)
+    }
+
+    fn inverse(&self, result: u64, right: u64) -> Inverse {
+        Inverse::Left(result ^ right)
+    }
+}
+
+/// The operators the `operators` param can choose from.
+const OPERATORS: &[&dyn Operator] = &[&Add, &Mul, &Concat, &Sub, &Xor];
+
+fn operators_from_symbols(symbols: &str) -> Result<Vec<&'static dyn Operator>> {
+    symbols
+        .split(',')
+        .map(|symbol| {
+            OPERATORS
+                .iter()
+                .find(|op| op.symbol() == symbol.trim())
+                .copied()
+                .with_context(|| {
+                    format!(
+                        "Unknown operator `{}` (known: {})",
+                        symbol,
+                        OPERATORS.iter().map(|op| op.symbol()).join(" ")
+                    )
+                })
+        })
+        .collect()
+}
+
+/// Every value the numbers can evaluate to, with the operators that give it.
+fn evaluations<'a>(
+    numbers: &[u64],
+    operators: &[&'a dyn Operator],
+) -> Vec<(u64, Vec<&'a dyn Operator>)> {
+    let Some((&last, rest)) = numbers.split_last() else {
+        return vec![];
+    };
+    if rest.is_empty() {
+        return vec![(last, vec![])];
+    }
+    let mut results = vec![];
+    for (value, used) in evaluations(rest, operators) {
+        for &op in operators {
+            if let Some(value) = op.apply(value, last) {
+                let mut used = used.clone();
+                used.push(op);
+                results.push((value, used));
+            }
+        }
+    }
+    results
+}
+
+/// Finds operators that make the numbers evaluate to `target`. Works backwards from the target:
+/// the last operator has to turn the value of the numbers before it into the target, and its
+/// inverse pins that value down or rules the operator out. Only operators without an inverse
+/// make the solver evaluate all the numbers before them.
+fn solve_equation<'a>(
+    target: u64,
+    numbers: &[u64],
+    operators: &[&'a dyn Operator],
+) -> Option<Vec<&'a dyn Operator>> {
+    let (&last, rest) = numbers.split_last()?;
+    if rest.is_empty() {
+        return (last == target).then(Vec::new);
+    }
+    for &op in operators {
+        let found = match op.inverse(target, last) {
+            Inverse::Left(left) => solve_equation(left, rest, operators),
+            Inverse::Impossible => None,
+            Inverse::Unknown => evaluations(rest, operators)
+                .into_iter()
+                .find(|&(value, _)| op.apply(value, last) == Some(target))
+                .map(|(_, used)| used),
+        };
+        if let Some(mut used) = found {
+            used.push(op);
+            return Some(used);
+        }
+    }
+    None
+}
+
+/// The equation with its operators filled in, like `292 = 11 + 6 * 16 + 20`.
+fn explain(input: &Input, used: &[&dyn Operator]) -> String {
+    let mut text = format!("{} = {}", input.expected_answer, input.numbers[0]);
+    for (op, number) in used.iter().zip(&input.numbers[1..]) {
+        write!(text, " {} {}", op.symbol(), number).unwrap();
+    }
+    text
+}
+
+fn solve<R: BufRead>(reader: R, operators: &[&dyn Operator]) -> Result<u64> {
+    let inputs = timing::parse(|| read_input(reader))?;
+
+    let mut answer = 0u64;
+    for input in inputs {
+        if let Some(used) = solve_equation(input.expected_answer, &input.numbers, operators) {
+            debug!("{}", explain(&input, &used));
+            answer = answer
+                .checked_add(input.expected_answer)
+                .context("The calibration result does not fit into 64 bits")?;
+        }
+    }
+
+    Ok(answer)
+}
+
+fn part1<R: BufRead>(reader: R) -> Result<u64> {
+    solve(reader, &[&Add, &Mul])
+}
+
+fn part2<R: BufRead>(reader: R, operators: &str) -> Result<u64> {
+    solve(reader, &operators_from_symbols(operators)?)
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    fn day(&self) -> &'static str {
+        DAY
+    }
+
+    fn params(&self) -> &'static [Param] {
+        PARAMS
+    }
+
+    fn part1(&self, reader: &mut dyn BufRead, _params: &Params) -> Result<String> {
+        Ok(part1(reader)?.to_string())
+    }
+
+    fn part2(&self, reader: &mut dyn BufRead, params: &Params) -> Result<String> {
+        Ok(part2(reader, &params.get::<String>("operators")?)?.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn symbols(used: &[&dyn Operator]) -> Vec<&'static str> {
+        used.iter().map(|op| op.symbol()).collect()
+    }
+
+    #[test]
+    fn witnesses_explain_the_equations() -> Result<()> {
+        let operators: &[&dyn Operator] = &[&Add, &Mul, &Concat];
+        let mut explained = vec![];
+        for input in read_input(TEST.as_bytes())? {
+            if let Some(used) = solve_equation(input.expected_answer, &input.numbers, operators) {
+                explained.push(explain(&input, &used));
+            }
+        }
+        assert_eq!(
+            vec![
+                "190 = 10 * 19",
+                "3267 = 81 * 40 + 27",
+                "156 = 15 || 6",
+                "7290 = 6 * 8 || 6 * 15",
+                "192 = 17 || 8 + 14",
+                "292 = 11 + 6 * 16 + 20",
+            ],
+            explained
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn inverses_undo_the_operators() {
+        for op in OPERATORS {
+            let pairs = [
+                (0, 0),
+                (0, 7),
+                (12, 0),
+                (12, 345),
+                (99, 10),
+                (0, u64::MAX),
+                (1, u64::MAX),
+            ];
+            for (left, right) in pairs {
+                let result = op.apply(left, right);
+                match result.map(|result| op.inverse(result, right)) {
+                    Some(Inverse::Left(found)) => assert_eq!(result, op.apply(found, right)),
+                    Some(inverse) => assert_eq!(Inverse::Unknown, inverse, "{}", op.symbol()),
+                    None => {}
+                }
+            }
+        }
+        assert_eq!(Inverse::Impossible, Concat.inverse(1234, 5));
+        assert_eq!(Inverse::Left(12), Concat.inverse(1234, 34));
+        assert_eq!(Inverse::Impossible, Mul.inverse(7, 2));
+    }
+
+    #[test]
+    fn other_operators_and_overflow() {
+        let operators = operators_from_symbols("-,^").unwrap();
+        assert_eq!(
+            Some(vec!["^", "-"]),
+            solve_equation(1, &[5, 3, 5], &operators).map(|used| symbols(&used))
+        );
+        assert!(operators_from_symbols("+,/").is_err());
+
+        // an operator without inverse makes the solver evaluate the numbers before it
+        struct Max;
+        impl Operator for Max {
+            fn symbol(&self) -> &'static str {
+                "max"
+            }
+
+            fn apply(&self, left: u64, right: u64) -> Option<u64> {
+                Some(left.max(right))
+            }
+        }
+        let used = solve_equation(12, &[3, 4, 2], &[&Add, &Mul, &Max]).unwrap();
+        assert_eq!(vec!["*", "max"], symbols(&used));
+
+        let operators: &[&dyn Operator] = &[&Add, &Mul, &Concat];
+        assert_eq!(
+            None,
+            solve_equation(5, &[u64::MAX, 2, 3], operators).map(|_| ())
+        );
+        assert!(solve_equation(u64::MAX, &[u64::MAX / 5, 5, 0], operators).is_some());
+    }
+}
@@ -0,0 +1,248 @@
+use crate::grid::Grid;
+use crate::point::Point;
+use crate::{debug, examples, timing, Param, ParamKind, Params, Solution};
+use anyhow::*;
+use itertools::Itertools;
+use std::collections::BTreeMap;
+use std::io::BufRead;
+use std::result::Result::Ok;
+
+const DAY: &str = "08";
+
+const PARAMS: &[Param] = &[Param {
+    name: "harmonics",
+    default: "2",
+    kind: ParamKind::Numbers,
+    help: "Ratios of the distances to the farther and the nearer antenna at which part 1 finds \
+           antinodes, e.g. `2,3`",
+}];
+
+const TEST: &str = "\
+............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............
+";
+
+examples! {
+    Day08;
+    example: TEST => { part1: "14", part2: "34" },
+}
+
+/// The antennas of each frequency.
+type Antennas = BTreeMap<char, Vec<Point>>;
+
+/// For each frequency, every antinode with the antenna pairs that produce it.
+type Antinodes = BTreeMap<char, BTreeMap<Point, Vec<(Point, Point)>>>;
+
+const FREQUENCIES: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+
+/// Where a pair of antennas produces antinodes.
+#[derive(Debug, Clone, Copy)]
+enum Harmonics<'a> {
+    /// Beyond either antenna, where the distance to the farther one is the given multiple of the
+    /// distance to the nearer one.
+    Ratios(&'a [i64]),
+    /// At every grid position in line with the pair.
+    All,
+}
+
+fn gcd(a: i64, b: i64) -> i64 {
+    if b == 0 {
+        a.abs()
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// The antinodes of the antennas at `a` and `b` that are on the map. They lie on the line
+/// through the antennas, at whole multiples of the smallest step along it from one grid position
+/// to the next.
+fn antinodes(map: &Grid<char>, a: Point, b: Point, harmonics: Harmonics) -> Vec<Point> {
+    let distance = b - a;
+    let steps = gcd(distance.row, distance.col);
+    let step = Point::new(distance.row / steps, distance.col / steps);
+
+    match harmonics {
+        Harmonics::Ratios(ratios) => ratios
+            .iter()
+            // with the nearer antenna `steps / (ratio - 1)` steps away
+            .filter(|&&ratio| steps % (ratio - 1) == 0)
+            .flat_map(|&ratio| {
+                let beyond = step * (steps / (ratio - 1));
+                [a - beyond, b + beyond]
+            })
+            .filter(|&p| map.contains(p))
+            .collect(),
+        Harmonics::All => {
+            let walk = |from: Point, step: Point| {
+                std::iter::successors(Some(from), move |&p| Some(p + step))
+                    .take_while(|&p| map.contains(p))
+            };
+            walk(a, -step).chain(walk(a + step, step)).collect()
+        }
+    }
+}
+
+fn find_antinodes(map: &Grid<char>, antennas: &Antennas, harmonics: Harmonics) -> Antinodes {
+    let mut found = Antinodes::new();
+    for (&frequency, positions) in antennas {
+        let of_frequency = found.entry(frequency).or_default();
+        for (&a, &b) in positions.iter().tuple_combinations() {
+            for antinode in antinodes(map, a, b, harmonics) {
+                of_frequency.entry(antinode).or_default().push((a, b));
+            }
+        }
+    }
+    found
+}
+
+fn parse_input<R: BufRead>(reader: R) -> Result<(Grid<char>, Antennas)> {
+    let map = Grid::read(DAY, reader, &format!(".{}", FREQUENCIES))?;
+    let mut antennas = Antennas::new();
+    for (p, &c) in map.iter() {
+        if c != '.' {
+            antennas.entry(c).or_default().push(p);
+        }
+    }
+
+    Ok((map, antennas))
+}
+
+fn solve<R: BufRead>(reader: R, harmonics: Harmonics) -> Result<usize> {
+    let (map, antennas) = timing::parse(|| parse_input(reader))?;
+
+    let antinodes = find_antinodes(&map, &antennas, harmonics);
+    for (frequency, of_frequency) in &antinodes {
+        for (antinode, pairs) in of_frequency {
+            let pairs = pairs.iter().map(|(a, b)| format!("{}-{}", a, b)).join(", ");
+            debug!("{} antinode at {} from {}", frequency, antinode, pairs);
+        }
+    }
+
+    let result = antinodes
+        .values()
+        .flat_map(|of_frequency| of_frequency.keys())
+        .unique()
+        .count();
+
+    Ok(result)
+}
+
+fn part1<R: BufRead>(reader: R, harmonics: &str) -> Result<usize> {
+    let ratios = harmonics
+        .split(',')
+        .map(|ratio| {
+            let ratio = ratio.trim();
+            match ratio.parse::<i64>() {
+                Ok(ratio) if ratio >= 2 => Ok(ratio),
+                _ => bail!(
+                    "A harmonic has to be a whole ratio of at least 2, not `{}`",
+                    ratio
+                ),
+            }
+        })
+        .collect::<Result<Vec<_>>>()?;
+    solve(reader, Harmonics::Ratios(&ratios))
+}
+
+fn part2<R: BufRead>(reader: R) -> Result<usize> {
+    solve(reader, Harmonics::All)
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    fn day(&self) -> &'static str {
+        DAY
+    }
+
+    fn params(&self) -> &'static [Param] {
+        PARAMS
+    }
+
+    fn part1(&self, reader: &mut dyn BufRead, params: &Params) -> Result<String> {
+        Ok(part1(reader, &params.get::<String>("harmonics")?)?.to_string())
+    }
+
+    fn part2(&self, reader: &mut dyn BufRead, _params: &Params) -> Result<String> {
+        Ok(part2(reader)?.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn antinodes_walk_the_reduced_line() {
+        let map = Grid::new(12, 12, '.');
+        let (a, b) = (Point::new(4, 4), Point::new(6, 8));
+        // the one beyond `b` is off the map
+        assert_eq!(
+            vec![Point::new(2, 0)],
+            antinodes(&map, a, b, Harmonics::Ratios(&[2]))
+        );
+        // the step between the antennas reduces to (1, 2)
+        assert_eq!(
+            vec![
+                Point::new(4, 4),
+                Point::new(3, 2),
+                Point::new(2, 0),
+                Point::new(5, 6),
+                Point::new(6, 8),
+                Point::new(7, 10),
+            ],
+            antinodes(&map, a, b, Harmonics::All)
+        );
+        // only a line with an even number of steps has antinodes of ratio 3
+        assert_eq!(
+            vec![Point::new(3, 2), Point::new(7, 10)],
+            antinodes(&map, a, b, Harmonics::Ratios(&[3]))
+        );
+        assert!(antinodes(&map, a, Point::new(7, 7), Harmonics::Ratios(&[3])).is_empty());
+
+        let map = Grid::new(1000, 1000, '.');
+        let line = antinodes(&map, Point::new(500, 1), Point::new(501, 1), Harmonics::All);
+        assert_eq!(1000, line.len());
+    }
+
+    #[test]
+    fn reports_the_pairs_of_each_antinode() -> Result<()> {
+        let (map, antennas) = parse_input(TEST.as_bytes())?;
+        let antinodes = find_antinodes(&map, &antennas, Harmonics::Ratios(&[2]));
+        assert_eq!(10, antinodes[&'0'].len());
+        assert_eq!(
+            vec![(Point::new(2, 5), Point::new(4, 4))],
+            antinodes[&'0'][&Point::new(0, 6)]
+        );
+        // both frequencies have an antinode at (1, 3)
+        assert_eq!(
+            vec![(Point::new(2, 5), Point::new(3, 7))],
+            antinodes[&'0'][&Point::new(1, 3)]
+        );
+        assert_eq!(
+            vec![(Point::new(5, 6), Point::new(9, 9))],
+            antinodes[&'A'][&Point::new(1, 3)]
+        );
+
+        // every antenna is an antinode of the pairs it belongs to
+        let antinodes = find_antinodes(&map, &antennas, Harmonics::All);
+        assert_eq!(
+            vec![
+                (Point::new(5, 6), Point::new(8, 8)),
+                (Point::new(8, 8), Point::new(9, 9)),
+            ],
+            antinodes[&'A'][&Point::new(8, 8)]
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,86 @@
+use crate::grid::Grid;
+use crate::point::Point;
+use crate::{examples, timing, Params, Solution};
+use anyhow::*;
+use itertools::Itertools;
+use std::io::BufRead;
+
+const DAY: &str = "10";
+
+const TEST: &str = "\
+89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732
+";
+
+examples! {
+    Day10;
+    example: TEST => { part1: "36", part2: "81" },
+}
+
+fn read_input<R: BufRead>(reader: R) -> Result<Grid<i32>> {
+    let input = Grid::read(DAY, reader, "0123456789")?;
+    Ok(input.map(|&ch| (ch as u8 - b'0') as i32))
+}
+
+fn traverse(cur_pos: Point, field: &Grid<i32>, expected_val: i32) -> Vec<Point> {
+    if field.get(cur_pos) != Some(&expected_val) {
+        return vec![];
+    }
+
+    if expected_val == 9 {
+        return vec![cur_pos];
+    }
+
+    let mut points = vec![];
+    for n in field.neighbours4(cur_pos) {
+        let s = traverse(n, field, expected_val + 1);
+        points.extend(s.iter());
+    }
+
+    points
+}
+
+fn sol<R: BufRead>(reader: R, unique: bool) -> Result<usize> {
+    let input = timing::parse(|| read_input(reader))?;
+
+    let mut res = 0;
+    for p in input.points() {
+        let mut traversal_result = traverse(p, &input, 0);
+        if unique {
+            traversal_result = traversal_result.iter().unique().copied().collect();
+        }
+        res += traversal_result.len();
+    }
+
+    Ok(res)
+}
+
+fn part1<R: BufRead>(reader: R) -> Result<usize> {
+    sol(reader, true)
+}
+
+fn part2<R: BufRead>(reader: R) -> Result<usize> {
+    sol(reader, false)
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    fn day(&self) -> &'static str {
+        DAY
+    }
+
+    fn part1(&self, reader: &mut dyn BufRead, _params: &Params) -> Result<String> {
+        Ok(part1(reader)?.to_string())
+    }
+
+    fn part2(&self, reader: &mut dyn BufRead, _params: &Params) -> Result<String> {
+        Ok(part2(reader)?.to_string())
+    }
+}
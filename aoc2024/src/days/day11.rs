@@ -0,0 +1,77 @@
+use crate::{examples, lines, timing, Params, Solution};
+use anyhow::*;
+use cached::proc_macro::cached;
+use std::io::BufRead;
+
+const DAY: &str = "11";
+
+const TEST: &str = "\
+125 17
+";
+
+examples! {
+    Day11;
+    example: TEST => { part1: "55312", part2: "65601038650482" },
+}
+
+#[cached]
+fn simulate(number: u64, remaining_steps: u32) -> u64 {
+    if remaining_steps == 0 {
+        return 1;
+    }
+    let remaining_steps = remaining_steps - 1;
+    match number {
+        0 => simulate(1, remaining_steps),
+        x => {
+            let len = x.ilog10() + 1;
+            if len % 2 == 0 {
+                let half = 10u64.pow(len / 2);
+                let (left_num, right_num) = (x / half, x % half);
+                simulate(left_num, remaining_steps) + simulate(right_num, remaining_steps)
+            } else {
+                simulate(x * 2024, remaining_steps)
+            }
+        }
+    }
+}
+
+fn parse_input<R: BufRead>(reader: R) -> Result<Vec<u64>> {
+    let Some(line) = lines(DAY, reader).next().transpose()? else {
+        bail!("Day {} input is empty", DAY);
+    };
+    line.text
+        .split_whitespace()
+        .map(|x| line.parse::<u64>(x))
+        .collect()
+}
+
+fn solve<R: BufRead>(reader: R, steps: u32) -> Result<u64> {
+    let numbers = timing::parse(|| parse_input(reader))?;
+    let result = numbers.iter().map(|f| simulate(*f, steps)).sum::<u64>();
+
+    Ok(result)
+}
+
+fn part1<R: BufRead>(reader: R) -> Result<u64> {
+    solve(reader, 25)
+}
+
+fn part2<R: BufRead>(reader: R) -> Result<u64> {
+    solve(reader, 75)
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    fn day(&self) -> &'static str {
+        DAY
+    }
+
+    fn part1(&self, reader: &mut dyn BufRead, _params: &Params) -> Result<String> {
+        Ok(part1(reader)?.to_string())
+    }
+
+    fn part2(&self, reader: &mut dyn BufRead, _params: &Params) -> Result<String> {
+        Ok(part2(reader)?.to_string())
+    }
+}
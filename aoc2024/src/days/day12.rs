@@ -0,0 +1,190 @@
+use crate::grid::Grid;
+use crate::point::{Direction, Point};
+use crate::{examples, timing, Params, Solution};
+use anyhow::*;
+use itertools::Itertools;
+use std::collections::{HashMap, HashSet};
+use std::io::BufRead;
+
+const DAY: &str = "12";
+
+const TEST1: &str = "\
+AAAA
+BBCD
+BBCC
+EEEC
+";
+
+const TEST2: &str = "\
+OOOOO
+OXOXO
+OOOOO
+OXOXO
+OOOOO
+";
+
+const TEST3: &str = "\
+RRRRIICCFF
+RRRRIICCCF
+VVRRRCCFFF
+VVRCCCJFFF
+VVVVCJJCFE
+VVIVCCJJEE
+VVIIICJJEE
+MIIIIIJJEE
+MIIISIJEEE
+MMMISSJEEE
+";
+
+const TEST4: &str = "\
+AAAAAA
+AAABBA
+AAABBA
+ABBAAA
+ABBAAA
+AAAAAA
+";
+
+const TEST5: &str = "\
+EEEEE
+EXXXX
+EEEEE
+EXXXX
+EEEEE
+";
+
+examples! {
+    Day12;
+    example1: TEST1 => { part1: "140", part2: "80" },
+    example2: TEST2 => { part1: "772", part2: "436" },
+    example3: TEST3 => { part1: "1930", part2: "1206" },
+    example4: TEST4 => { part2: "368" },
+    example5: TEST5 => { part2: "236" },
+}
+
+fn colorize(visited: &mut Grid<u64>, field: &Grid<char>, pos: Point, color: u64, exp_char: char) {
+    if field.get(pos) != Some(&exp_char) {
+        return;
+    }
+
+    if visited[pos] != 0 {
+        return;
+    }
+    visited[pos] = color;
+
+    for n in field.neighbours4(pos) {
+        colorize(visited, field, n, color, exp_char);
+    }
+}
+
+fn read_input<R: BufRead>(reader: R) -> Result<Grid<char>> {
+    Grid::read(DAY, reader, "ABCDEFGHIJKLMNOPQRSTUVWXYZ")
+}
+
+fn group_field(field: Grid<char>) -> HashMap<u64, HashSet<Point>> {
+    let mut visited = field.map(|_| 0);
+
+    for (color, (p, c)) in (1..).zip(field.iter()) {
+        colorize(&mut visited, &field, p, color, *c);
+    }
+
+    let mut groups = HashMap::new();
+    for (p, v) in visited.iter() {
+        groups.entry(*v).or_insert(HashSet::new()).insert(p);
+    }
+    groups
+}
+
+/// A fence on the `side` of a plot. Positions are doubled plot coordinates, so that the fence
+/// lies between the plot and its neighbour.
+#[derive(Clone, Debug, Hash)]
+struct Fence {
+    pos: Point,
+    side: Direction,
+}
+
+fn get_fences(g: &HashSet<Point>) -> Vec<Fence> {
+    let mut fences = vec![];
+    for &elem in g {
+        for side in Direction::ALL {
+            let n = elem.step(side);
+            if !g.contains(&n) {
+                fences.push(Fence {
+                    pos: elem + n,
+                    side,
+                });
+            }
+        }
+    }
+    fences
+}
+
+fn count_sides_from_fences(fences: Vec<Fence>) -> usize {
+    let mut sides = 0;
+
+    for (side, grouped_fences) in fences.into_iter().into_group_map_by(|k| k.side) {
+        let aligned_groups = grouped_fences.into_iter().into_group_map_by(|f| {
+            if side.is_horizontal() {
+                f.pos.col
+            } else {
+                f.pos.row
+            }
+        });
+
+        for aligned_fences in aligned_groups.into_values() {
+            let number_sides = aligned_fences
+                .into_iter()
+                .sorted_by(|a, b| a.pos.cmp(&b.pos))
+                .tuple_windows::<(Fence, Fence)>()
+                .filter(|(a, b)| a.pos.manhattan_distance(b.pos) != 2)
+                .count()
+                + 1;
+            sides += number_sides;
+        }
+    }
+
+    sides
+}
+
+fn solve<R: BufRead>(reader: R, is_part_2: bool) -> Result<u64> {
+    let lines = timing::parse(|| read_input(reader))?;
+
+    let groups = group_field(lines);
+
+    let mut result = 0;
+    for g in groups.values() {
+        let fences = get_fences(g);
+        let surrounding = if is_part_2 {
+            count_sides_from_fences(fences)
+        } else {
+            fences.len()
+        };
+        result += surrounding as u64 * g.len() as u64;
+    }
+
+    Ok(result)
+}
+
+fn part1<R: BufRead>(reader: R) -> Result<u64> {
+    solve(reader, false)
+}
+
+fn part2<R: BufRead>(reader: R) -> Result<u64> {
+    solve(reader, true)
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    fn day(&self) -> &'static str {
+        DAY
+    }
+
+    fn part1(&self, reader: &mut dyn BufRead, _params: &Params) -> Result<String> {
+        Ok(part1(reader)?.to_string())
+    }
+
+    fn part2(&self, reader: &mut dyn BufRead, _params: &Params) -> Result<String> {
+        Ok(part2(reader)?.to_string())
+    }
+}
@@ -0,0 +1,176 @@
+use crate::{examples, lines, timing, Params, Solution};
+use anyhow::*;
+use itertools::Itertools;
+use regex::Regex;
+use std::io::BufRead;
+
+const DAY: &str = "13";
+
+const TEST1: &str = "\
+Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
+
+Button A: X+26, Y+66
+Button B: X+67, Y+21
+Prize: X=12748, Y=12176
+
+Button A: X+17, Y+86
+Button B: X+84, Y+37
+Prize: X=7870, Y=6450
+
+Button A: X+69, Y+23
+Button B: X+27, Y+71
+Prize: X=18641, Y=10279
+";
+
+examples! {
+    Day13;
+    example1: TEST1 => { part1: "480" },
+}
+
+type Equation = (i64, i64, i64);
+
+#[derive(Debug)]
+struct EquationPair {
+    first_eq: Equation,
+    second_eq: Equation,
+}
+
+fn solve(eq_pair: EquationPair) -> Option<u64> {
+    let cross_x = EquationPair {
+        first_eq: (
+            eq_pair.first_eq.0 * eq_pair.second_eq.0,
+            eq_pair.first_eq.1 * eq_pair.second_eq.0,
+            eq_pair.first_eq.2 * eq_pair.second_eq.0,
+        ),
+        second_eq: (
+            eq_pair.second_eq.0 * eq_pair.first_eq.0,
+            eq_pair.second_eq.1 * eq_pair.first_eq.0,
+            eq_pair.second_eq.2 * eq_pair.first_eq.0,
+        ),
+    };
+
+    let subtracted = (
+        cross_x.first_eq.0 - cross_x.second_eq.0,
+        cross_x.first_eq.1 - cross_x.second_eq.1,
+        cross_x.first_eq.2 - cross_x.second_eq.2,
+    );
+
+    assert_eq!(subtracted.0, 0);
+
+    if subtracted.2 % subtracted.1 != 0 {
+        return None;
+    }
+
+    let y_sol = subtracted.2 / subtracted.1;
+
+    if (eq_pair.first_eq.2 - (y_sol * eq_pair.first_eq.1)) % eq_pair.first_eq.0 != 0 {
+        return None;
+    }
+
+    let x_sol = (eq_pair.first_eq.2 - (y_sol * eq_pair.first_eq.1)) / eq_pair.first_eq.0;
+
+    assert_eq!(
+        eq_pair.first_eq.0 * x_sol + eq_pair.first_eq.1 * y_sol,
+        eq_pair.first_eq.2
+    );
+    assert_eq!(
+        eq_pair.second_eq.0 * x_sol + eq_pair.second_eq.1 * y_sol,
+        eq_pair.second_eq.2
+    );
+
+    if x_sol < 0 || y_sol < 0 {
+        None
+    } else {
+        Some(x_sol as u64 * 3 + y_sol as u64)
+    }
+}
+
+fn read_input<R: BufRead>(reader: R) -> Result<Vec<EquationPair>> {
+    let mut p = vec![];
+    let regex = Regex::new(r"X.?(\d+), Y.?(\d+)$")?;
+    for l in lines(DAY, reader) {
+        let l = l?;
+        let res = regex.captures(l.text.as_str());
+        if let Some(x) = res {
+            let n1 = l.parse::<i64>(&x[1])?;
+            let n2 = l.parse::<i64>(&x[2])?;
+            p.push((n1, n2));
+        } else if !l.text.is_empty() {
+            return Err(l.error(1, "expected `..: X.., Y..`"));
+        }
+    }
+    if p.len() % 3 != 0 {
+        bail!(
+            "Day {} input ends with an incomplete machine, expected two buttons and a prize",
+            DAY
+        );
+    }
+
+    Ok(p.into_iter()
+        .tuples::<((i64, i64), (i64, i64), (i64, i64))>()
+        .map(|(fl, sl, rl)| EquationPair {
+            first_eq: (fl.0, sl.0, rl.0),
+            second_eq: (fl.1, sl.1, rl.1),
+        })
+        .collect())
+}
+
+const PART_2_RESULT_OFFSET: i64 = 10000000000000;
+
+fn part1<R: BufRead>(reader: R) -> Result<u64> {
+    let equations = timing::parse(|| read_input(reader))?;
+
+    let result = equations
+        .into_iter()
+        .map(solve)
+        .map(|r| r.unwrap_or(0))
+        .sum();
+
+    Ok(result)
+}
+
+fn part2<R: BufRead>(reader: R) -> Result<u64> {
+    let equations = timing::parse(|| read_input(reader))?;
+
+    let offseted_equations: Vec<_> = equations
+        .into_iter()
+        .map(|eq| EquationPair {
+            first_eq: (
+                eq.first_eq.0,
+                eq.first_eq.1,
+                eq.first_eq.2 + PART_2_RESULT_OFFSET,
+            ),
+            second_eq: (
+                eq.second_eq.0,
+                eq.second_eq.1,
+                eq.second_eq.2 + PART_2_RESULT_OFFSET,
+            ),
+        })
+        .collect();
+
+    let result = offseted_equations
+        .into_iter()
+        .map(solve)
+        .map(|r| r.unwrap_or(0))
+        .sum();
+
+    Ok(result)
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    fn day(&self) -> &'static str {
+        DAY
+    }
+
+    fn part1(&self, reader: &mut dyn BufRead, _params: &Params) -> Result<String> {
+        Ok(part1(reader)?.to_string())
+    }
+
+    fn part2(&self, reader: &mut dyn BufRead, _params: &Params) -> Result<String> {
+        Ok(part2(reader)?.to_string())
+    }
+}
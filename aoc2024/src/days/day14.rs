@@ -0,0 +1,186 @@
+use crate::{examples, lines, timing, Param, ParamKind, Params, Solution};
+use anyhow::*;
+use itertools::Itertools;
+use regex::Regex;
+use std::cmp::Ordering;
+use std::io::BufRead;
+
+const DAY: &str = "14";
+
+const PARAMS: &[Param] = &[
+    Param {
+        name: "width",
+        default: "101",
+        kind: ParamKind::Positive,
+        help: "Width of the area the robots move in",
+    },
+    Param {
+        name: "height",
+        default: "103",
+        kind: ParamKind::Positive,
+        help: "Height of the area the robots move in",
+    },
+];
+
+const TEST1: &str = "\
+p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=10,3 v=-1,2
+p=2,0 v=2,-1
+p=0,0 v=1,3
+p=3,0 v=-2,-2
+p=7,6 v=-1,-3
+p=3,0 v=-1,-2
+p=9,3 v=2,3
+p=7,3 v=-1,2
+p=2,4 v=2,-3
+p=9,5 v=-3,-3
+";
+
+examples! {
+    Day14;
+    example1: TEST1 => { params: { width: "11", height: "7" }, part1: "12" },
+}
+
+struct InputLine {
+    px: i64,
+    py: i64,
+    vx: i64,
+    vy: i64,
+}
+
+#[derive(Debug)]
+struct Simulated {
+    x: i64,
+    y: i64,
+}
+
+fn parse_input<R: BufRead>(reader: R) -> Result<Vec<InputLine>> {
+    let mut p = vec![];
+    let regex = Regex::new(r"p=(\d+),(\d+) v=(-?\d+),(-?\d+)$")?;
+    for l in lines(DAY, reader) {
+        let l = l?;
+        let res = regex
+            .captures(l.text.as_str())
+            .ok_or_else(|| l.error(1, "expected `p=X,Y v=DX,DY`"))?;
+        let px = l.parse::<i64>(&res[1])?;
+        let py = l.parse::<i64>(&res[2])?;
+        let vx = l.parse::<i64>(&res[3])?;
+        let vy = l.parse::<i64>(&res[4])?;
+        p.push(InputLine { px, py, vx, vy });
+    }
+
+    Ok(p)
+}
+
+fn simulate(l: &InputLine, steps: u64, width: i64, height: i64) -> Simulated {
+    let x = (((l.px + (steps as i64) * l.vx) % width) + width) % width;
+    let y = (((l.py + (steps as i64) * l.vy) % height) + height) % height;
+    Simulated { x, y }
+}
+
+#[allow(dead_code)]
+fn visualize_area(position: &[Simulated]) -> Vec<String> {
+    let mut area = [['.'; 120]; 120];
+
+    for p in position {
+        area[p.x as usize][p.y as usize] = 'x';
+    }
+
+    let plotted: Vec<_> = area.iter().map(String::from_iter).collect();
+
+    plotted
+}
+
+fn calc_distance(positions: &[Simulated]) -> i64 {
+    /* Okay, this one was fun!
+
+    At First, I tried to check if there is a horizontal line that is bigger than a limit,
+    but that was not successful.
+
+    I now calculate the sum of Manhattan distance and hope that the Christmas tree clutters.
+    As it turned out, there is a huge drop from the clusters to non-clustered (100k vs 130k),
+    so this seems to work well enough for me.
+    */
+
+    let manhattan_distance = positions
+        .iter()
+        .map(|f| {
+            positions
+                .iter()
+                .map(|g| (f.x - g.x).abs() + (f.y - g.y).abs())
+                .sum::<i64>()
+        })
+        .sum::<i64>();
+
+    manhattan_distance
+}
+
+fn part1<R: BufRead>(reader: R, width: i64, height: i64) -> Result<u64> {
+    let input = timing::parse(|| parse_input(reader))?;
+
+    let simulations = input
+        .iter()
+        .map(|l| simulate(l, 100, width, height))
+        .collect::<Vec<Simulated>>();
+
+    let quadrants = simulations
+        .iter()
+        .map(|simulated| {
+            (
+                (
+                    simulated.x.cmp(&(width / 2)),
+                    simulated.y.cmp(&(height / 2)),
+                ),
+                1,
+            )
+        })
+        .filter(|((x, y), _b)| *x != Ordering::Equal && *y != Ordering::Equal)
+        .into_group_map();
+    // an empty quadrant makes the product 0
+    let result = if quadrants.len() == 4 {
+        quadrants.values().map(|y| y.iter().sum::<u64>()).product()
+    } else {
+        0
+    };
+
+    Ok(result)
+}
+
+fn part2<R: BufRead>(reader: R, width: i64, height: i64) -> Result<u64> {
+    let input = timing::parse(|| parse_input(reader))?;
+
+    let minimal_dist_steps = (0..100_000)
+        .map(|steps| {
+            let simulations = input
+                .iter()
+                .map(|l| simulate(l, steps, width, height))
+                .collect::<Vec<Simulated>>();
+
+            calc_distance(&simulations)
+        })
+        .position_min()
+        .unwrap();
+
+    Ok(minimal_dist_steps as u64)
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    fn day(&self) -> &'static str {
+        DAY
+    }
+
+    fn params(&self) -> &'static [Param] {
+        PARAMS
+    }
+
+    fn part1(&self, reader: &mut dyn BufRead, params: &Params) -> Result<String> {
+        Ok(part1(reader, params.get("width")?, params.get("height")?)?.to_string())
+    }
+
+    fn part2(&self, reader: &mut dyn BufRead, params: &Params) -> Result<String> {
+        Ok(part2(reader, params.get("width")?, params.get("height")?)?.to_string())
+    }
+}
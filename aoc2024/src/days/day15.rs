@@ -0,0 +1,253 @@
+use crate::grid::Grid;
+use crate::point::{Direction, Point};
+use crate::{examples, lines, timing, Params, Solution};
+use anyhow::*;
+use std::collections::HashSet;
+use std::io::BufRead;
+use std::result::Result::Ok;
+
+const DAY: &str = "15";
+
+const TEST1: &str = "\
+##########
+#..O..O.O#
+#......O.#
+#.OO..O.O#
+#..O@..O.#
+#O#..O...#
+#O..O..O.#
+#.OO.O.OO#
+#....O...#
+##########
+
+<vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
+vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
+><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
+<<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
+^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
+^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
+>^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
+<><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
+^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
+v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^
+";
+
+examples! {
+    Day15;
+    example1: TEST1 => { part1: "10092", part2: "9021" },
+}
+
+fn parse_input<R: BufRead>(reader: R) -> Result<(Grid<char>, Vec<Direction>)> {
+    let mut labyrinth = vec![];
+    let mut movement = vec![];
+    for l in lines(DAY, reader) {
+        let l = l?;
+        let allowed = if l.text.starts_with('#') {
+            "#.O@"
+        } else {
+            "<>^v"
+        };
+        if let Some((column, c)) = l
+            .text
+            .chars()
+            .enumerate()
+            .find(|(_, c)| !allowed.contains(*c))
+        {
+            return Err(l.error(
+                column + 1,
+                format!("unexpected `{}`, expected one of `{}`", c, allowed),
+            ));
+        }
+        if l.text.starts_with('#') {
+            labyrinth.push(l.text.chars().collect::<Vec<_>>());
+        } else {
+            for c in l.text.chars() {
+                movement.push(Direction::try_from(c)?);
+            }
+        }
+    }
+
+    let labyrinth = Grid::from_rows(labyrinth)?;
+    let robots = labyrinth.find_all(&'@').count();
+    if robots != 1 {
+        bail!("Day {} input has {} robots `@` instead of one", DAY, robots);
+    }
+
+    Ok((labyrinth, movement))
+}
+
+#[derive(Clone)]
+struct LabObjectDefinition {
+    id: u64,
+    left_pos: Point,
+    width: usize,
+}
+
+impl LabObjectDefinition {
+    fn is_in(&self, pos: &Point) -> bool {
+        pos.row == self.left_pos.row
+            && self.left_pos.col <= pos.col
+            && pos.col < self.left_pos.col + self.width as i64
+    }
+
+    fn get_leftmost_position(&self) -> Point {
+        self.left_pos
+    }
+
+    fn get_rightmost_position(&self) -> Point {
+        self.left_pos + Direction::Right.offset() * (self.width as i64 - 1)
+    }
+
+    fn get_all_positions(&self) -> Vec<Point> {
+        (0..self.width)
+            .map(|i| self.left_pos + Direction::Right.offset() * i as i64)
+            .collect()
+    }
+}
+
+type LabBox = LabObjectDefinition;
+type Wall = LabObjectDefinition;
+
+fn reinterpret_labyrinth(lab: Grid<char>, width: usize) -> Result<(Point, Vec<LabBox>, Vec<Wall>)> {
+    let mut boxes = vec![];
+    let mut walls = vec![];
+    let mut start_pos = None;
+
+    for (p, char) in lab.iter() {
+        let resolved_pos = Point::new(p.row, width as i64 * p.col);
+        match char {
+            '@' => start_pos = Some(resolved_pos),
+            'O' => boxes.push(LabBox {
+                id: boxes.len() as u64,
+                left_pos: resolved_pos,
+                width,
+            }),
+            '#' => walls.push(Wall {
+                id: boxes.len() as u64,
+                left_pos: resolved_pos,
+                width,
+            }),
+            '.' => {}
+            _ => bail!(
+                "Day {} labyrinth has an unexpected `{}` at {:?}",
+                DAY,
+                char,
+                p
+            ),
+        }
+    }
+
+    let start_pos = start_pos.ok_or_else(|| anyhow!("Day {} labyrinth has no robot `@`", DAY))?;
+    Ok((start_pos, boxes, walls))
+}
+
+fn evaluate_boxes_result(boxes: &[LabBox]) -> u64 {
+    let mut res = 0;
+
+    for b in boxes {
+        let pos = b.get_leftmost_position();
+        res += pos.row * 100 + pos.col;
+    }
+
+    res as u64
+}
+
+fn try_if_new_pos_allowed(
+    pos: Point,
+    boxes: &[LabBox],
+    walls: &[Wall],
+    movement: Direction,
+) -> Result<HashSet<u64>> {
+    if walls.iter().any(|w| w.is_in(&pos)) {
+        return Err(anyhow!("Nope, box"));
+    }
+
+    match boxes.iter().find(|b| b.is_in(&pos)) {
+        None => Ok(HashSet::new()),
+        Some(b) => {
+            let mut potential: HashSet<_> = HashSet::new();
+            potential.insert(b.id);
+            let relevant_positions = match movement {
+                Direction::Left => vec![b.get_leftmost_position()],
+                Direction::Right => vec![b.get_rightmost_position()],
+                _ => b.get_all_positions(),
+            };
+            for box_pos in relevant_positions {
+                let new_pos = box_pos.step(movement);
+
+                let res = try_if_new_pos_allowed(new_pos, boxes, walls, movement)?;
+                for r in res {
+                    potential.insert(r);
+                }
+            }
+            Ok(potential)
+        }
+    }
+}
+
+fn apply_step(
+    pos: Point,
+    boxes: &[LabBox],
+    walls: &[Wall],
+    movement: Direction,
+) -> (Point, Vec<LabBox>) {
+    let potential_pos = pos.step(movement);
+    let potential_movement_result = try_if_new_pos_allowed(potential_pos, boxes, walls, movement);
+    match potential_movement_result {
+        Ok(blocks) => {
+            let new_boxes = boxes
+                .iter()
+                .map(|b| {
+                    if blocks.contains(&b.id) {
+                        LabBox {
+                            left_pos: b.left_pos.step(movement),
+                            ..*b
+                        }
+                    } else {
+                        b.clone()
+                    }
+                })
+                .collect();
+            (potential_pos, new_boxes)
+        }
+        Err(_) => (pos, boxes.to_vec()),
+    }
+}
+
+fn solve<R: BufRead>(reader: R, width: usize) -> Result<u64> {
+    let (labyrinth, movement) = timing::parse(|| parse_input(reader))?;
+
+    let (mut pos, mut boxes, walls) = reinterpret_labyrinth(labyrinth, width)?;
+
+    for dir in movement {
+        (pos, boxes) = apply_step(pos, &boxes, &walls, dir);
+    }
+
+    let result = evaluate_boxes_result(&boxes);
+
+    Ok(result)
+}
+
+fn part1<R: BufRead>(reader: R) -> Result<u64> {
+    solve(reader, 1)
+}
+
+fn part2<R: BufRead>(reader: R) -> Result<u64> {
+    solve(reader, 2)
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    fn day(&self) -> &'static str {
+        DAY
+    }
+
+    fn part1(&self, reader: &mut dyn BufRead, _params: &Params) -> Result<String> {
+        Ok(part1(reader)?.to_string())
+    }
+
+    fn part2(&self, reader: &mut dyn BufRead, _params: &Params) -> Result<String> {
+        Ok(part2(reader)?.to_string())
+    }
+}
@@ -0,0 +1,238 @@
+use crate::grid::Grid;
+use crate::point::{Direction, Point};
+use crate::search::{self, Paths};
+use crate::{examples, timing, Export, Params, Solution};
+use anyhow::*;
+use std::collections::HashSet;
+use std::io::BufRead;
+
+const DAY: &str = "16";
+
+const TEST1: &str = "\
+###############
+#.......#....E#
+#.#.###.#.###.#
+#.....#.#...#.#
+#.###.#####.#.#
+#.#.#.......#.#
+#.#.#####.###.#
+#...........#.#
+###.#.#####.#.#
+#...#.....#.#.#
+#.#.#.###.#.#.#
+#.....#...#.#.#
+#.###.#.#.#.#.#
+#S..#.....#...#
+###############
+";
+
+const TEST2: &str = "\
+#################
+#...#...#...#..E#
+#.#.#.#.#.#.#.#.#
+#.#.#.#...#...#.#
+#.#.#.#.###.#.#.#
+#...#.#.#.....#.#
+#.#.#.#.#.#####.#
+#.#...#.#.#.....#
+#.#.#####.#.###.#
+#.#.#.......#...#
+#.#.###.#####.###
+#.#.#...#.....#.#
+#.#.#.#####.###.#
+#.#.#.........#.#
+#.#.#.#########.#
+#S#.............#
+#################
+";
+
+const EXPORTS: &[Export] = &[Export {
+    name: "routes",
+    reads_input: true,
+    help: "The labyrinth with the tiles on the best routes marked as `O`",
+}];
+
+examples! {
+    Day16;
+    example1: TEST1 => { part1: "7036", part2: "45" },
+    example2: TEST2 => { part2: "64" },
+}
+
+fn parse_input<R: BufRead>(reader: R) -> Result<Grid<char>> {
+    let labyrinth = Grid::read(DAY, reader, "#.SE")?;
+    for needle in ['S', 'E'] {
+        if labyrinth.find(&needle).is_none() {
+            bail!("Day {} input has no `{}`", DAY, needle);
+        }
+    }
+
+    Ok(labyrinth)
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+struct Reindeer {
+    position: Point,
+    orientation: Direction,
+}
+
+impl Reindeer {
+    /// Turning costs 1000 points, stepping forward onto a free tile 1.
+    fn moves(self, labyrinth: &Grid<char>) -> Vec<(Reindeer, u64)> {
+        let Reindeer {
+            position,
+            orientation,
+        } = self;
+        let mut moves = vec![
+            (
+                Reindeer {
+                    position,
+                    orientation: orientation.turn_left(),
+                },
+                1000,
+            ),
+            (
+                Reindeer {
+                    position,
+                    orientation: orientation.turn_right(),
+                },
+                1000,
+            ),
+        ];
+        let next = position.step(orientation);
+        if labyrinth.get(next).is_some_and(|&c| c != '#') {
+            moves.push((
+                Reindeer {
+                    position: next,
+                    orientation,
+                },
+                1,
+            ));
+        }
+        moves
+    }
+}
+
+/// Searches all cheapest routes from `S`, facing east, to `E` in any orientation. Returns their
+/// cost and the search, whose predecessors hold every one of them.
+fn best_routes(labyrinth: &Grid<char>) -> Result<(u64, Paths<Reindeer>)> {
+    let start_pos = labyrinth.find(&'S').unwrap();
+    let end_pos = labyrinth.find(&'E').unwrap();
+
+    let start_reindeer = Reindeer {
+        position: start_pos,
+        orientation: Direction::Right,
+    };
+
+    let paths = search::astar(
+        [start_reindeer],
+        |reindeer| reindeer.moves(labyrinth),
+        |reindeer| reindeer.position.manhattan_distance(end_pos) as u64,
+        |reindeer| reindeer.position == end_pos,
+    );
+
+    let Some(end_reindeer) = paths.goals().first() else {
+        bail!("There is no route from S to E");
+    };
+    let min_cost = paths.distance(end_reindeer).unwrap();
+    Ok((min_cost, paths))
+}
+
+/// The tiles that are part of at least one of the best routes.
+fn tiles_on_best_routes(paths: &Paths<Reindeer>) -> HashSet<Point> {
+    paths
+        .on_optimal_paths(paths.goals().iter().copied())
+        .into_iter()
+        .map(|reindeer| reindeer.position)
+        .collect()
+}
+
+/// The labyrinth with the free tiles on the best routes marked as `O`.
+fn draw_routes(labyrinth: &Grid<char>, tiles: &HashSet<Point>) -> Grid<char> {
+    let mut drawing = labyrinth.clone();
+    for &p in tiles {
+        if drawing[p] == '.' {
+            drawing[p] = 'O';
+        }
+    }
+    drawing
+}
+
+fn part1<R: BufRead>(reader: R) -> Result<u64> {
+    let labyrinth = timing::parse(|| parse_input(reader))?;
+
+    let (min_cost, _) = best_routes(&labyrinth)?;
+
+    Ok(min_cost)
+}
+
+fn part2<R: BufRead>(reader: R) -> Result<usize> {
+    let labyrinth = timing::parse(|| parse_input(reader))?;
+
+    let (_, paths) = best_routes(&labyrinth)?;
+    let tiles = tiles_on_best_routes(&paths);
+
+    Ok(tiles.len())
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    fn day(&self) -> &'static str {
+        DAY
+    }
+
+    fn part1(&self, reader: &mut dyn BufRead, _params: &Params) -> Result<String> {
+        Ok(part1(reader)?.to_string())
+    }
+
+    fn part2(&self, reader: &mut dyn BufRead, _params: &Params) -> Result<String> {
+        Ok(part2(reader)?.to_string())
+    }
+
+    fn exports(&self) -> &'static [Export] {
+        EXPORTS
+    }
+
+    fn export(&self, reader: &mut dyn BufRead, name: &str, _params: &Params) -> Result<String> {
+        match name {
+            "routes" => {
+                let labyrinth = parse_input(reader)?;
+                let (_, paths) = best_routes(&labyrinth)?;
+                let drawing = draw_routes(&labyrinth, &tiles_on_best_routes(&paths));
+                Ok(format!("{}\n", drawing))
+            }
+            _ => bail!("Day {} has no export `{}`", DAY, name),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn draws_the_best_routes() -> Result<()> {
+        let drawing = Day16.export(&mut TEST1.as_bytes(), "routes", &Params::default())?;
+        assert_eq!(
+            "\
+###############
+#.......#....E#
+#.#.###.#.###O#
+#.....#.#...#O#
+#.###.#####.#O#
+#.#.#.......#O#
+#.#.#####.###O#
+#..OOOOOOOOO#O#
+###O#O#####O#O#
+#OOO#O....#O#O#
+#O#O#O###.#O#O#
+#OOOOO#...#O#O#
+#O###.#.#.#O#O#
+#S..#.....#OOO#
+###############
+",
+            drawing
+        );
+        Ok(())
+    }
+}
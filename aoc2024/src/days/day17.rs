@@ -0,0 +1,1017 @@
+use crate::{debug, examples, lines, timing, Export, Param, ParamKind, Params, Solution};
+use anyhow::*;
+use itertools::Itertools;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::collections::{BTreeSet, HashMap};
+use std::fmt::{Display, Formatter, Write};
+use std::io::BufRead;
+use std::result::Result::Ok;
+use std::str::FromStr;
+
+const DAY: &str = "17";
+
+const PARAMS: &[Param] = &[
+    Param {
+        name: "breakpoints",
+        default: "",
+        kind: ParamKind::Numbers,
+        help: "Comma-separated instruction positions the trace export stops at",
+    },
+    Param {
+        name: "watch",
+        default: "",
+        kind: ParamKind::Text,
+        help: "Comma-separated registers (A, B, C) the trace export stops at when they change",
+    },
+    Param {
+        name: "budget",
+        default: "10000",
+        kind: ParamKind::Number,
+        help: "Number of instructions the trace export runs at most",
+    },
+    Param {
+        name: "seed",
+        default: "17",
+        kind: ParamKind::Number,
+        help: "Seed of the program the random export writes",
+    },
+];
+
+const EXPORTS: &[Export] = &[
+    Export {
+        name: "disassembly",
+        reads_input: true,
+        help: "The program as mnemonics, with the combo operands resolved",
+    },
+    Export {
+        name: "trace",
+        reads_input: true,
+        help: "The executed instructions with the registers after each of them",
+    },
+    Export {
+        name: "assemble",
+        reads_input: true,
+        help: "Reads the input as assembly source and writes it in the puzzle input format",
+    },
+    Export {
+        name: "random",
+        reads_input: false,
+        help: "A random program that halts, in the puzzle input format",
+    },
+];
+
+const TEST1: &str = "\
+Register A: 729
+Register B: 0
+Register C: 0
+
+Program: 0,1,5,4,3,0
+";
+
+const TEST2: &str = "\
+Register A: 2024
+Register B: 0
+Register C: 0
+
+Program: 0,3,5,4,3,0
+";
+
+examples! {
+    Day17;
+    example1: TEST1 => { part1: "4,6,3,5,6,3,5,2,1,0" },
+    example2: TEST2 => { part2: "117440" },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct ProgramState {
+    reg_a: u64,
+    reg_b: u64,
+    reg_c: u64,
+    pc: usize,
+}
+
+fn parse_input<R: BufRead>(reader: R) -> Result<(ProgramState, Vec<u8>)> {
+    let (mut reg_a, mut reg_b, mut reg_c, mut instructions) = (None, None, None, None);
+    for line in lines(DAY, reader) {
+        let line = line?;
+        if line.text.is_empty() {
+            continue;
+        }
+        let (label, value) = line.split_once(": ")?;
+        match label {
+            "Register A" => reg_a = Some(line.parse::<u64>(value)?),
+            "Register B" => reg_b = Some(line.parse::<u64>(value)?),
+            "Register C" => reg_c = Some(line.parse::<u64>(value)?),
+            "Program" => {
+                let program = value
+                    .split(",")
+                    .map(|x| match line.parse::<u8>(x)? {
+                        v @ 0..=7 => Ok(v),
+                        _ => Err(line.error_at(x, "expected a 3-bit number")),
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                if program.len() % 2 != 0 {
+                    return Err(line.error_at_end("expected an operand after the last opcode"));
+                }
+                instructions = Some(program);
+            }
+            _ => return Err(line.error(1, format!("unexpected `{}`", label))),
+        }
+    }
+
+    let missing = |what| anyhow!("Day {} input has no {}", DAY, what);
+    let state = ProgramState {
+        reg_a: reg_a.ok_or_else(|| missing("Register A"))?,
+        reg_b: reg_b.ok_or_else(|| missing("Register B"))?,
+        reg_c: reg_c.ok_or_else(|| missing("Register C"))?,
+        pc: 0,
+    };
+    let instructions = instructions.ok_or_else(|| missing("Program"))?;
+
+    Ok((state, instructions))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Register {
+    A,
+    B,
+    C,
+}
+
+impl FromStr for Register {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "A" | "a" => Ok(Register::A),
+            "B" | "b" => Ok(Register::B),
+            "C" | "c" => Ok(Register::C),
+            _ => bail!("`{}` is not a register, expected A, B or C", s),
+        }
+    }
+}
+
+impl Display for Register {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// A combo operand: 0 to 3 stand for themselves, 4 to 6 for the registers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Combo {
+    Literal(u8),
+    Register(Register),
+}
+
+impl Combo {
+    fn decode(operand: u8) -> Result<Self> {
+        match operand {
+            0..=3 => Ok(Combo::Literal(operand)),
+            4 => Ok(Combo::Register(Register::A)),
+            5 => Ok(Combo::Register(Register::B)),
+            6 => Ok(Combo::Register(Register::C)),
+            _ => bail!("invalid combo operand {}", operand),
+        }
+    }
+}
+
+impl Display for Combo {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Combo::Literal(value) => write!(f, "{}", value),
+            Combo::Register(register) => write!(f, "{}", register),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Instruction {
+    Adv(Combo),
+    Bxl(u8),
+    Bst(Combo),
+    Jnz(u8),
+    Bxc,
+    Out(Combo),
+    Bdv(Combo),
+    Cdv(Combo),
+}
+
+impl Instruction {
+    fn decode(opcode: u8, operand: u8) -> Result<Self> {
+        let combo = || Combo::decode(operand);
+        Ok(match opcode {
+            0 => Instruction::Adv(combo()?),
+            1 => Instruction::Bxl(operand),
+            2 => Instruction::Bst(combo()?),
+            3 => Instruction::Jnz(operand),
+            4 => Instruction::Bxc,
+            5 => Instruction::Out(combo()?),
+            6 => Instruction::Bdv(combo()?),
+            7 => Instruction::Cdv(combo()?),
+            _ => bail!("invalid opcode {}", opcode),
+        })
+    }
+
+    /// The instruction at `pc`, `None` when the program halts there.
+    fn at(instructions: &[u8], pc: usize) -> Result<Option<Self>> {
+        match (instructions.get(pc), instructions.get(pc + 1)) {
+            (Some(&opcode), Some(&operand)) => Instruction::decode(opcode, operand)
+                .map(Some)
+                .with_context(|| format!("Invalid instruction at {}", pc)),
+            _ => Ok(None),
+        }
+    }
+
+    /// What the instruction does, in pseudocode.
+    fn meaning(&self) -> String {
+        match self {
+            Instruction::Adv(combo) => format!("A = A >> {}", combo),
+            Instruction::Bxl(literal) => format!("B = B ^ {}", literal),
+            Instruction::Bst(combo) => format!("B = {} % 8", combo),
+            Instruction::Jnz(literal) => format!("if A != 0 goto {}", literal),
+            Instruction::Bxc => "B = B ^ C".to_string(),
+            Instruction::Out(combo) => format!("output {} % 8", combo),
+            Instruction::Bdv(combo) => format!("B = A >> {}", combo),
+            Instruction::Cdv(combo) => format!("C = A >> {}", combo),
+        }
+    }
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Instruction::Adv(combo) => write!(f, "adv {}", combo),
+            Instruction::Bxl(literal) => write!(f, "bxl {}", literal),
+            Instruction::Bst(combo) => write!(f, "bst {}", combo),
+            Instruction::Jnz(literal) => write!(f, "jnz {}", literal),
+            Instruction::Bxc => write!(f, "bxc"),
+            Instruction::Out(combo) => write!(f, "out {}", combo),
+            Instruction::Bdv(combo) => write!(f, "bdv {}", combo),
+            Instruction::Cdv(combo) => write!(f, "cdv {}", combo),
+        }
+    }
+}
+
+/// One line per instruction with its position, mnemonic and meaning.
+fn disassemble(instructions: &[u8]) -> Result<String> {
+    let mut listing = String::new();
+    for pc in (0..instructions.len()).step_by(2) {
+        let instruction = Instruction::at(instructions, pc)?.unwrap();
+        let text = instruction.to_string();
+        writeln!(
+            listing,
+            "{:>3}: {:<6} ; {}",
+            pc,
+            text,
+            instruction.meaning()
+        )?;
+    }
+    Ok(listing)
+}
+
+impl ProgramState {
+    fn register(&self, register: Register) -> u64 {
+        match register {
+            Register::A => self.reg_a,
+            Register::B => self.reg_b,
+            Register::C => self.reg_c,
+        }
+    }
+
+    fn combo(&self, combo: Combo) -> u64 {
+        match combo {
+            Combo::Literal(value) => value as u64,
+            Combo::Register(register) => self.register(register),
+        }
+    }
+
+    /// The `dv` instructions: A divided by 2 to the power of the operand.
+    fn divide_a(&self, combo: Combo) -> u64 {
+        u32::try_from(self.combo(combo))
+            .ok()
+            .and_then(|shift| self.reg_a.checked_shr(shift))
+            .unwrap_or(0)
+    }
+}
+
+impl Display for ProgramState {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "pc={} A={} B={} C={}",
+            self.pc, self.reg_a, self.reg_b, self.reg_c
+        )
+    }
+}
+
+/// Runs the instruction at the program counter. `None` once the program halted.
+fn simulate(
+    state: ProgramState,
+    instructions: &[u8],
+) -> Result<Option<(ProgramState, Option<u8>)>> {
+    let Some(instruction) = Instruction::at(instructions, state.pc)? else {
+        return Ok(None);
+    };
+    let next = ProgramState {
+        pc: state.pc + 2,
+        ..state
+    };
+
+    let mut output = None;
+
+    let new_state = match instruction {
+        Instruction::Adv(combo) => ProgramState {
+            reg_a: state.divide_a(combo),
+            ..next
+        },
+        Instruction::Bxl(literal) => ProgramState {
+            reg_b: state.reg_b ^ literal as u64,
+            ..next
+        },
+        Instruction::Bst(combo) => ProgramState {
+            reg_b: state.combo(combo) % 8,
+            ..next
+        },
+        Instruction::Jnz(literal) => ProgramState {
+            pc: if state.reg_a == 0 {
+                next.pc
+            } else {
+                literal as usize
+            },
+            ..state
+        },
+        Instruction::Bxc => ProgramState {
+            reg_b: state.reg_b ^ state.reg_c,
+            ..next
+        },
+        Instruction::Out(combo) => {
+            output = Some((state.combo(combo) % 8) as u8);
+            next
+        }
+        Instruction::Bdv(combo) => ProgramState {
+            reg_b: state.divide_a(combo),
+            ..next
+        },
+        Instruction::Cdv(combo) => ProgramState {
+            reg_c: state.divide_a(combo),
+            ..next
+        },
+    };
+
+    Ok(Some((new_state, output)))
+}
+
+fn simulate_program(initial_program_state: ProgramState, instructions: &[u8]) -> Result<Vec<u8>> {
+    let mut current_program_state = initial_program_state;
+
+    let mut full_output = vec![];
+
+    while let Some((state, output)) = simulate(current_program_state, instructions)? {
+        current_program_state = state;
+        if let Some(content) = output {
+            full_output.push(content);
+        }
+    }
+    Ok(full_output)
+}
+
+/// Why [`Debugger::run`] stopped.
+#[derive(Debug, PartialEq, Eq)]
+enum Stop {
+    Halted,
+    /// Before running the instruction at this position.
+    Breakpoint(usize),
+    Watch {
+        register: Register,
+        old: u64,
+        new: u64,
+    },
+    /// The run executed as many instructions as it was allowed to.
+    Budget,
+}
+
+impl Display for Stop {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Stop::Halted => write!(f, "halted"),
+            Stop::Breakpoint(pc) => write!(f, "breakpoint at {}", pc),
+            Stop::Watch { register, old, new } => {
+                write!(f, "{} changed from {} to {}", register, old, new)
+            }
+            Stop::Budget => write!(f, "instruction budget used up"),
+        }
+    }
+}
+
+/// One executed instruction, with the state after it.
+#[derive(Debug, Clone)]
+struct Step {
+    pc: usize,
+    instruction: Instruction,
+    state: ProgramState,
+    output: Option<u8>,
+}
+
+impl Display for Step {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let instruction = self.instruction.to_string();
+        write!(f, "{:>3}: {:<6} -> {}", self.pc, instruction, self.state)?;
+        if let Some(value) = self.output {
+            write!(f, " out {}", value)?;
+        }
+        std::fmt::Result::Ok(())
+    }
+}
+
+/// Runs a program instruction by instruction, keeping the trace of everything it executed.
+struct Debugger<'a> {
+    instructions: &'a [u8],
+    state: ProgramState,
+    breakpoints: BTreeSet<usize>,
+    watches: Vec<Register>,
+    trace: Vec<Step>,
+}
+
+impl<'a> Debugger<'a> {
+    fn new(state: ProgramState, instructions: &'a [u8]) -> Self {
+        Debugger {
+            instructions,
+            state,
+            breakpoints: BTreeSet::new(),
+            watches: vec![],
+            trace: vec![],
+        }
+    }
+
+    fn output(&self) -> Vec<u8> {
+        self.trace.iter().filter_map(|step| step.output).collect()
+    }
+
+    /// Runs one instruction. `None` when the program already halted.
+    fn step(&mut self) -> Result<Option<&Step>> {
+        let pc = self.state.pc;
+        let Some((state, output)) = simulate(self.state, self.instructions)? else {
+            return Ok(None);
+        };
+        let instruction = Instruction::at(self.instructions, pc)?.unwrap();
+        self.state = state;
+        self.trace.push(Step {
+            pc,
+            instruction,
+            state,
+            output,
+        });
+        Ok(self.trace.last())
+    }
+
+    /// Runs until the program halts, reaches a breakpoint, changes a watched register or has run
+    /// `budget` instructions. The first instruction always runs, so a run can continue from the
+    /// breakpoint it stopped at.
+    fn run(&mut self, budget: usize) -> Result<Stop> {
+        for _ in 0..budget {
+            let before = self.state;
+            if self.step()?.is_none() {
+                return Ok(Stop::Halted);
+            }
+            for &register in &self.watches {
+                let (old, new) = (before.register(register), self.state.register(register));
+                if old != new {
+                    return Ok(Stop::Watch { register, old, new });
+                }
+            }
+            if self.breakpoints.contains(&self.state.pc) {
+                return Ok(Stop::Breakpoint(self.state.pc));
+            }
+        }
+        Ok(Stop::Budget)
+    }
+}
+
+/// Runs the program in the debugger configured by the params and lists the executed instructions
+/// up to where it stopped.
+fn trace(state: ProgramState, instructions: &[u8], params: &Params) -> Result<String> {
+    let mut debugger = Debugger::new(state, instructions);
+    let list = |name| -> Result<Vec<String>> {
+        let value: String = params.get(name)?;
+        Ok(value
+            .split(',')
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(str::to_string)
+            .collect())
+    };
+    for pc in list("breakpoints")? {
+        let pc = pc
+            .parse()
+            .with_context(|| format!("Invalid breakpoint `{}`", pc))?;
+        debugger.breakpoints.insert(pc);
+    }
+    for register in list("watch")? {
+        debugger.watches.push(register.parse()?);
+    }
+
+    let stop = debugger.run(params.get("budget")?)?;
+
+    let mut listing = format!("  start: {}\n", state);
+    for step in &debugger.trace {
+        writeln!(listing, "{}", step)?;
+    }
+    writeln!(
+        listing,
+        "stopped after {} instructions: {}",
+        debugger.trace.len(),
+        stop
+    )?;
+    writeln!(listing, "output: {}", debugger.output().iter().join(","))?;
+    Ok(listing)
+}
+
+/// The mnemonics, indexed by opcode.
+const MNEMONICS: [&str; 8] = ["adv", "bxl", "bst", "jnz", "bxc", "out", "bdv", "cdv"];
+
+/// The program in the puzzle input format.
+fn to_input(state: ProgramState, instructions: &[u8]) -> String {
+    format!(
+        "Register A: {}\nRegister B: {}\nRegister C: {}\n\nProgram: {}\n",
+        state.reg_a,
+        state.reg_b,
+        state.reg_c,
+        instructions.iter().join(",")
+    )
+}
+
+/// A random program that always halts, for fuzzing the machine against the assembler. It is
+/// straight-line code, or a loop that ends in `jnz 0`. A loop has a single `adv` that shifts A by
+/// 1 to 3 bits, so it runs at most 64 rounds.
+fn random_program(rng: &mut StdRng) -> (ProgramState, Vec<u8>) {
+    let mut instructions = vec![];
+    for _ in 0..rng.random_range(1..8) {
+        let opcode = [1, 2, 4, 5, 6, 7][rng.random_range(0..6)];
+        let operand = match opcode {
+            1 => rng.random_range(0..8),
+            // the disassembly drops the ignored operand of `bxc`
+            4 => 0,
+            _ => rng.random_range(0..7),
+        };
+        instructions.extend([opcode, operand]);
+    }
+    if rng.random_bool(0.5) {
+        let at = rng.random_range(0..=instructions.len() / 2) * 2;
+        instructions.splice(at..at, [0, rng.random_range(1..4)]);
+        instructions.extend([3, 0]);
+    }
+    let mut register = || rng.random::<u64>() >> rng.random_range(0..64);
+    let state = ProgramState {
+        reg_a: register(),
+        reg_b: register(),
+        reg_c: register(),
+        pc: 0,
+    };
+    (state, instructions)
+}
+
+/// Turns assembly source into the puzzle input format. Every line holds one instruction, like
+/// `out B` or `jnz loop`, optionally after a `label:`, and everything after a `;` is a comment.
+/// Combo operands are 0 to 3 or a register, `jnz` jumps to a literal or a label, `bxc` needs no
+/// operand. `Register A: 729` lines set the registers, which start at 0 otherwise. Numbers in
+/// place of labels must match the position of the instruction, so disassemblies assemble again.
+fn assemble<R: BufRead>(reader: R) -> Result<String> {
+    let lines = lines(DAY, reader).collect::<Result<Vec<_>>>()?;
+    let mut registers = [0; 3];
+    let mut labels = HashMap::new();
+    let mut statements = vec![];
+
+    for line in &lines {
+        let mut code = line.text.split(';').next().unwrap().trim();
+        if let Some(register) = code.strip_prefix("Register ") {
+            let (name, value) = register
+                .split_once(':')
+                .ok_or_else(|| line.error_at_end("expected `:`"))?;
+            let register = Register::from_str(name.trim()).map_err(|e| line.error_at(name, e))?;
+            registers[register as usize] = line.parse(value)?;
+            continue;
+        }
+        if let Some((label, rest)) = code.split_once(':') {
+            let label = label.trim();
+            let position = statements.len() * 2;
+            if let Ok(expected) = label.parse::<usize>() {
+                if expected != position {
+                    let message = format!("the instruction is at {}, not {}", position, expected);
+                    return Err(line.error_at(label, message));
+                }
+            } else if !label.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+                || !label.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+            {
+                return Err(line.error_at(label, format!("`{}` is not a label", label)));
+            } else if labels.insert(label, position).is_some() {
+                return Err(line.error_at(label, format!("label `{}` is defined twice", label)));
+            }
+            code = rest.trim();
+        }
+        if !code.is_empty() {
+            let (mnemonic, operand) = match code.split_once(char::is_whitespace) {
+                Some((mnemonic, operand)) => (mnemonic, Some(operand.trim())),
+                None => (code, None),
+            };
+            statements.push((line, mnemonic, operand));
+        }
+    }
+
+    let mut instructions = vec![];
+    for (line, mnemonic, operand) in statements {
+        let opcode = MNEMONICS
+            .iter()
+            .position(|&m| m == mnemonic)
+            .ok_or_else(|| {
+                line.error_at(mnemonic, format!("unknown instruction `{}`", mnemonic))
+            })?;
+        let literal = |operand| match line.parse(operand)? {
+            value @ 0..=7 => Ok(value),
+            _ => Err(line.error_at(operand, "expected a 3-bit number")),
+        };
+        let operand = match (opcode, operand) {
+            (4, None) => 0,
+            (_, None) => return Err(line.error_at_end(format!("`{}` needs an operand", mnemonic))),
+            (3, Some(label)) if label.starts_with(|c: char| !c.is_ascii_digit()) => {
+                match labels.get(label) {
+                    Some(&position @ 0..=7) => position as u8,
+                    Some(position) => {
+                        let message = format!(
+                            "label `{}` is at {}, `jnz` can only jump to 0 to 7",
+                            label, position
+                        );
+                        return Err(line.error_at(label, message));
+                    }
+                    None => return Err(line.error_at(label, format!("unknown label `{}`", label))),
+                }
+            }
+            (1 | 3 | 4, Some(operand)) => literal(operand)?,
+            (_, Some(operand)) => match Register::from_str(operand) {
+                Ok(register) => register as u8 + 4,
+                Err(_) => match literal(operand)? {
+                    value @ 0..=3 => value,
+                    _ => return Err(line.error_at(operand, "expected 0 to 3 or a register")),
+                },
+            },
+        };
+        instructions.extend([opcode as u8, operand]);
+    }
+
+    let [reg_a, reg_b, reg_c] = registers;
+    let state = ProgramState {
+        reg_a,
+        reg_b,
+        reg_c,
+        pc: 0,
+    };
+    Ok(to_input(state, &instructions))
+}
+
+fn part1<R: BufRead>(reader: R) -> Result<String> {
+    let (initial_program_state, instructions) = timing::parse(|| parse_input(reader))?;
+
+    let full_output = simulate_program(initial_program_state, &instructions)?;
+
+    Ok(full_output
+        .iter()
+        .map(u8::to_string)
+        .collect::<Vec<_>>()
+        .join(","))
+}
+
+/// Checks that the program is one loop over register A: it shifts A right by 3 with a single
+/// `adv 3`, outputs a single value and jumps back to the start with a `jnz 0` at its end. Every
+/// round then only sees the bits of A that are left, which is what the search relies on.
+fn validate_quine_shape(instructions: &[u8]) -> Result<()> {
+    let pairs: Vec<_> = instructions.chunks(2).map(|p| (p[0], p[1])).collect();
+    let count = |opcode| pairs.iter().filter(|(o, _)| *o == opcode).count();
+
+    ensure!(
+        pairs.last() == Some(&(3, 0)) && count(3) == 1,
+        "Part 2 needs a program with a single `jnz 0` at its end"
+    );
+    ensure!(
+        pairs.contains(&(0, 3)) && count(0) == 1,
+        "Part 2 needs a program that shifts A by 3 with a single `adv 3` per round"
+    );
+    ensure!(
+        count(5) == 1,
+        "Part 2 needs a program that outputs one value per round, it has {} `out`s",
+        count(5)
+    );
+    ensure!(
+        instructions.len() * 3 <= 64,
+        "A program of {} values needs more than the 64 bits of register A",
+        instructions.len()
+    );
+    Ok(())
+}
+
+/// Finds the lowest value of register A that makes the program output itself. The last round
+/// only sees the highest 3 bits of A, so they are chosen first for the last output, then the next
+/// 3 bits for the last two outputs and so on, backtracking when no chunk fits.
+fn find_quine_register(initial: ProgramState, instructions: &[u8]) -> Result<Option<u64>> {
+    fn search(
+        initial: ProgramState,
+        instructions: &[u8],
+        high_bits: u64,
+        outputs: usize,
+    ) -> Result<Option<u64>> {
+        if outputs > instructions.len() {
+            return Ok(Some(high_bits));
+        }
+        let expected = &instructions[instructions.len() - outputs..];
+        for chunk in 0..8 {
+            let reg_a = high_bits << 3 | chunk;
+            let output = simulate_program(ProgramState { reg_a, ..initial }, instructions)?;
+            if output == expected {
+                if let Some(found) = search(initial, instructions, reg_a, outputs + 1)? {
+                    return Ok(Some(found));
+                }
+            }
+        }
+        Ok(None)
+    }
+    search(initial, instructions, 0, 1)
+}
+
+fn part2<R: BufRead>(reader: R) -> Result<u64> {
+    let (initial_program_state, instructions) = timing::parse(|| parse_input(reader))?;
+
+    validate_quine_shape(&instructions)?;
+
+    let reg_a = find_quine_register(initial_program_state, &instructions)?
+        .context("No value of register A makes the program output itself")?;
+    debug!("Register A in octal: {:o}", reg_a);
+    Ok(reg_a)
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    fn day(&self) -> &'static str {
+        DAY
+    }
+
+    fn params(&self) -> &'static [Param] {
+        PARAMS
+    }
+
+    fn part1(&self, reader: &mut dyn BufRead, _params: &Params) -> Result<String> {
+        Ok(part1(reader)?.to_string())
+    }
+
+    fn part2(&self, reader: &mut dyn BufRead, _params: &Params) -> Result<String> {
+        Ok(part2(reader)?.to_string())
+    }
+
+    fn exports(&self) -> &'static [Export] {
+        EXPORTS
+    }
+
+    fn export(&self, reader: &mut dyn BufRead, name: &str, params: &Params) -> Result<String> {
+        match name {
+            "assemble" => return assemble(reader),
+            "random" => {
+                let mut rng = StdRng::seed_from_u64(params.get("seed")?);
+                let (state, instructions) = random_program(&mut rng);
+                return Ok(to_input(state, &instructions));
+            }
+            _ => {}
+        }
+        let (state, instructions) = parse_input(reader)?;
+        match name {
+            "disassembly" => disassemble(&instructions),
+            "trace" => trace(state, &instructions, params),
+            _ => bail!("Day {} has no export `{}`", DAY, name),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ParseError;
+
+    const STATE: ProgramState = ProgramState {
+        reg_a: 0,
+        reg_b: 0,
+        reg_c: 0,
+        pc: 0,
+    };
+
+    #[test]
+    fn finds_quines_that_use_all_registers() -> Result<()> {
+        // bst A; bxl 3; cdv B; adv 3; bxc; bxl 5; out B; jnz 0
+        let instructions = [2, 4, 1, 3, 7, 5, 0, 3, 4, 3, 1, 5, 5, 5, 3, 0];
+        validate_quine_shape(&instructions)?;
+        let reg_a = find_quine_register(STATE, &instructions)?.unwrap();
+        let state = ProgramState { reg_a, ..STATE };
+        assert_eq!(
+            instructions.to_vec(),
+            simulate_program(state, &instructions)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_programs_of_other_shapes() {
+        let error = |instructions: &[u8]| validate_quine_shape(instructions).unwrap_err();
+        // the first example shifts by 1
+        assert!(error(&[0, 1, 5, 4, 3, 0]).to_string().contains("adv 3"));
+        assert!(error(&[0, 3, 5, 4, 3, 2]).to_string().contains("jnz 0"));
+        assert!(error(&[0, 3, 3, 0, 5, 4, 3, 0])
+            .to_string()
+            .contains("jnz 0"));
+        assert!(error(&[0, 3, 0, 3, 5, 4, 3, 0])
+            .to_string()
+            .contains("adv 3"));
+        assert!(error(&[0, 3, 5, 4, 5, 4, 3, 0])
+            .to_string()
+            .contains("2 `out`s"));
+        assert!(error(&[0, 3, 2, 4, 3, 0]).to_string().contains("0 `out`s"));
+    }
+
+    #[test]
+    fn disassembles_with_resolved_operands() -> Result<()> {
+        let instructions = [2, 4, 1, 3, 7, 5, 0, 3, 4, 3, 1, 5, 5, 5, 3, 0];
+        assert_eq!(
+            vec![
+                "  0: bst A  ; B = A % 8",
+                "  2: bxl 3  ; B = B ^ 3",
+                "  4: cdv B  ; C = A >> B",
+                "  6: adv 3  ; A = A >> 3",
+                "  8: bxc    ; B = B ^ C",
+                " 10: bxl 5  ; B = B ^ 5",
+                " 12: out B  ; output B % 8",
+                " 14: jnz 0  ; if A != 0 goto 0",
+            ],
+            disassemble(&instructions)?.lines().collect::<Vec<_>>()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_combo_operands_are_errors() {
+        let error = disassemble(&[0, 3, 5, 7, 3, 0]).unwrap_err();
+        assert_eq!("Invalid instruction at 2", error.to_string());
+        let state = ProgramState { reg_a: 1, ..STATE };
+        assert!(simulate_program(state, &[1, 7, 2, 7]).is_err());
+        // a literal operand of 7 is fine
+        assert_eq!(vec![7], simulate_program(state, &[1, 7, 5, 5]).unwrap());
+    }
+
+    #[test]
+    fn debugger_stops_at_breakpoints_watches_and_budget() -> Result<()> {
+        let (state, instructions) = parse_input(TEST1.as_bytes())?;
+        let mut debugger = Debugger::new(state, &instructions);
+        let step = debugger.step()?.unwrap();
+        assert_eq!((0, 364), (step.pc, step.state.reg_a));
+
+        debugger.breakpoints.insert(4);
+        assert_eq!(Stop::Breakpoint(4), debugger.run(100)?);
+        assert_eq!(Stop::Breakpoint(4), debugger.run(100)?);
+        assert_eq!(vec![4, 6], debugger.output());
+
+        debugger.breakpoints.clear();
+        debugger.watches.push(Register::A);
+        let stop = debugger.run(100)?;
+        assert_eq!(
+            Stop::Watch {
+                register: Register::A,
+                old: 182,
+                new: 91
+            },
+            stop
+        );
+        assert_eq!(Stop::Budget, debugger.run(2)?);
+        debugger.watches.clear();
+        assert_eq!(Stop::Halted, debugger.run(100)?);
+        assert_eq!(simulate_program(state, &instructions)?, debugger.output());
+        assert!(debugger.step()?.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn trace_export_follows_the_params() -> Result<()> {
+        let params = Params::new(PARAMS, &[("breakpoints", "4"), ("budget", "50")])?;
+        let listing = Day17.export(&mut TEST2.as_bytes(), "trace", &params)?;
+        assert_eq!(
+            vec![
+                "  start: pc=0 A=2024 B=0 C=0",
+                "  0: adv 3  -> pc=2 A=253 B=0 C=0",
+                "  2: out A  -> pc=4 A=253 B=0 C=0 out 5",
+                "stopped after 2 instructions: breakpoint at 4",
+                "output: 5",
+            ],
+            listing.lines().collect::<Vec<_>>()
+        );
+        let params = Params::new(PARAMS, &[("budget", "1")])?;
+        let listing = Day17.export(&mut TEST2.as_bytes(), "trace", &params)?;
+        assert!(listing.contains("after 1 instructions: instruction budget used up"));
+        Ok(())
+    }
+
+    #[test]
+    fn random_programs_halt_and_survive_a_round_trip() -> Result<()> {
+        let mut rng = StdRng::seed_from_u64(17);
+        for _ in 0..500 {
+            let (state, instructions) = random_program(&mut rng);
+            let source = format!(
+                "Register A: {}\nRegister B: {}\nRegister C: {}\n{}",
+                state.reg_a,
+                state.reg_b,
+                state.reg_c,
+                disassemble(&instructions)?
+            );
+            let input = assemble(source.as_bytes())?;
+            assert_eq!(to_input(state, &instructions), input);
+            assert_eq!(
+                (state, instructions.clone()),
+                parse_input(input.as_bytes())?
+            );
+
+            let mut debugger = Debugger::new(state, &instructions);
+            let rounds = 65;
+            assert_eq!(Stop::Halted, debugger.run(rounds * instructions.len())?);
+            assert_eq!(simulate_program(state, &instructions)?, debugger.output());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn random_export_writes_the_program_of_the_seed() -> Result<()> {
+        let export = |seed| -> Result<String> {
+            let params = Params::new(PARAMS, &[("seed", seed)])?;
+            Day17.export(&mut "".as_bytes(), "random", &params)
+        };
+        let (state, instructions) = random_program(&mut StdRng::seed_from_u64(5));
+        assert_eq!(to_input(state, &instructions), export("5")?);
+        assert_ne!(export("5")?, export("6")?);
+        let input = export("6")?;
+        let (state, instructions) = parse_input(input.as_bytes())?;
+        assert_eq!(
+            Stop::Halted,
+            Debugger::new(state, &instructions).run(65 * instructions.len())?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn assembles_labels_and_comments() -> Result<()> {
+        let source = "\
+; the first example
+Register A: 729
+start:
+    adv 1
+    out A  ; print the lowest bits
+    jnz start
+";
+        assert_eq!(TEST1, assemble(source.as_bytes())?);
+        assert_eq!(
+            "Register A: 0\nRegister B: 0\nRegister C: 0\n\nProgram: 4,0,3,4,1,7\n",
+            assemble("bxc\n2: jnz end\nend: bxl 7\n".as_bytes())?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn assembler_errors_point_at_the_source() {
+        let error = |source: &str| {
+            let error = assemble(source.as_bytes()).unwrap_err();
+            let error = error.downcast_ref::<ParseError>().unwrap();
+            (error.line, error.column, error.message.clone())
+        };
+        assert_eq!(
+            (2, 1, "unknown instruction `mul`".to_string()),
+            error("adv 1\nmul 3\n")
+        );
+        assert_eq!(
+            (1, 5, "expected 0 to 3 or a register".to_string()),
+            error("out 5\n")
+        );
+        assert_eq!(
+            (1, 5, "unknown label `nowhere`".to_string()),
+            error("jnz nowhere\n")
+        );
+        assert_eq!(
+            (
+                6,
+                5,
+                "label `far` is at 8, `jnz` can only jump to 0 to 7".to_string()
+            ),
+            error("adv 1\nadv 1\nadv 1\nadv 1\nfar: out A\njnz far\n")
+        );
+        assert_eq!(
+            (2, 1, "label `a` is defined twice".to_string()),
+            error("a: out A\na: out B\n")
+        );
+        assert_eq!((1, 4, "`adv` needs an operand".to_string()), error("adv\n"));
+        assert_eq!(
+            (2, 1, "the instruction is at 2, not 4".to_string()),
+            error("0: out A\n4: out B\n")
+        );
+    }
+}
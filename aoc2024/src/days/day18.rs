@@ -0,0 +1,163 @@
+use crate::grid::Grid;
+use crate::point::Point;
+use crate::search;
+use crate::{examples, lines, timing, Param, ParamKind, Params, Solution};
+use anyhow::*;
+use std::io::BufRead;
+
+const DAY: &str = "18";
+
+const PARAMS: &[Param] = &[
+    Param {
+        name: "size",
+        default: "71",
+        kind: ParamKind::Positive,
+        help: "Width and height of the memory space",
+    },
+    Param {
+        name: "bytes",
+        default: "1024",
+        kind: ParamKind::Number,
+        help: "Number of fallen bytes to find the shortest path around in part 1",
+    },
+];
+
+const TEST: &str = "\
+5,4
+4,2
+4,5
+3,0
+2,1
+6,3
+2,4
+1,5
+0,6
+3,3
+2,6
+5,1
+1,2
+5,5
+2,5
+6,5
+1,4
+0,4
+6,4
+1,1
+6,1
+1,0
+0,5
+1,6
+2,0
+";
+
+examples! {
+    Day18;
+    example: TEST => { params: { size: "7", bytes: "12" }, part1: "22", part2: "6,1" },
+}
+
+fn parse_input<R: BufRead>(reader: R, field_size: usize) -> Result<Vec<(usize, usize)>> {
+    let fields = lines(DAY, reader)
+        .map(|l| {
+            let l = l?;
+            let (x, y) = l.split_once(",")?;
+            let (x, y) = (l.parse::<usize>(x)?, l.parse::<usize>(y)?);
+            if x >= field_size || y >= field_size {
+                return Err(l.error(
+                    1,
+                    format!("outside of the {0}x{0} memory space", field_size),
+                ));
+            }
+            Ok((x, y))
+        })
+        .collect::<Result<Vec<(usize, usize)>>>()?;
+
+    Ok(fields)
+}
+
+fn prepare_field(relevant_input_fields: Vec<(usize, usize)>, field_size: usize) -> Grid<char> {
+    let mut field = Grid::new(field_size, field_size, '.');
+    for f in relevant_input_fields {
+        field[Point::from(f)] = '#';
+    }
+    field
+}
+
+fn search_shortest_path(field: Grid<char>) -> Option<usize> {
+    let exit = Point::from((field.height() - 1, field.width() - 1));
+
+    let paths = search::bfs([Point::new(0, 0)], |&pos| {
+        field
+            .neighbours4(pos)
+            .filter(|&n| field[n] != '#')
+            .collect::<Vec<_>>()
+    });
+    paths.distance(&exit).map(|way| way as usize)
+}
+
+fn part1<R: BufRead>(reader: R, field_size: usize, steps: usize) -> Result<usize> {
+    let all_input_fields = timing::parse(|| parse_input(reader, field_size))?;
+    let relevant_input_fields = all_input_fields.into_iter().take(steps).collect::<Vec<_>>();
+
+    let field = prepare_field(relevant_input_fields, field_size);
+
+    search_shortest_path(field).ok_or_else(|| anyhow!("No path to the exit after {} bytes", steps))
+}
+
+fn part2<R: BufRead>(reader: R, field_size: usize) -> Result<String> {
+    let all_input_fields = timing::parse(|| parse_input(reader, field_size))?;
+    // the number of fallen bytes, from one to all of them
+    let fallen = (1..=all_input_fields.len()).collect::<Vec<_>>();
+    let blocking = fallen.partition_point(|f| {
+        let relevant_input_fields = all_input_fields
+            .clone()
+            .into_iter()
+            .take(*f)
+            .collect::<Vec<_>>();
+        let field = prepare_field(relevant_input_fields, field_size);
+        search_shortest_path(field).is_some()
+    });
+
+    let Some(coordinates) = all_input_fields.get(blocking) else {
+        bail!("The exit never becomes unreachable");
+    };
+
+    Ok(format!("{},{}", coordinates.0, coordinates.1))
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    fn day(&self) -> &'static str {
+        DAY
+    }
+
+    fn params(&self) -> &'static [Param] {
+        PARAMS
+    }
+
+    fn part1(&self, reader: &mut dyn BufRead, params: &Params) -> Result<String> {
+        Ok(part1(reader, params.get("size")?, params.get("bytes")?)?.to_string())
+    }
+
+    fn part2(&self, reader: &mut dyn BufRead, params: &Params) -> Result<String> {
+        Ok(part2(reader, params.get("size")?)?.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part2_fails_when_the_exit_stays_reachable() {
+        for input in ["", "1,1\n2,0\n"] {
+            let error = part2(input.as_bytes(), 3).unwrap_err();
+            assert_eq!("The exit never becomes unreachable", error.to_string());
+        }
+    }
+
+    #[test]
+    fn params_reject_an_empty_memory_space() {
+        assert!(Params::new(PARAMS, &[("size", "0")]).is_err());
+    }
+}
@@ -1,13 +1,9 @@
-use adv_code_2024::*;
+use crate::{examples, timing, Params, Solution};
 use anyhow::*;
 use cached::proc_macro::cached;
-use code_timing_macros::time_snippet;
-use const_format::concatcp;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::io::BufRead;
 
 const DAY: &str = "19";
-const INPUT_FILE: &str = concatcp!("input/", DAY, ".txt");
 
 const TEST1: &str = "\
 r, wr, b, g, bwu, rb, gb, br
@@ -22,6 +18,11 @@ brgr
 bbrgwb
 ";
 
+examples! {
+    Day19;
+    example1: TEST1 => { part1: "6", part2: "16" },
+}
+
 fn parse_input<R: BufRead>(reader: R) -> Result<(Vec<String>, Vec<String>)> {
     let mut available_patterns = vec![];
     let mut requested = vec![];
@@ -66,49 +67,39 @@ fn check_possible(pattern: String, available_patterns: Vec<String>) -> u64 {
     _check_possible(pattern, available_patterns, 0)
 }
 
-fn main() -> Result<()> {
-    start_day(DAY);
-
-    //region Part 1
-    println!("=== Part 1 ===");
-
-    fn part1<R: BufRead>(reader: R) -> Result<u64> {
-        let (available_patterns, requested) = parse_input(reader)?;
-
-        let num_possible = requested
-            .into_iter()
-            .map(|r| check_possible(r, available_patterns.clone()))
-            .filter(|b| *b > 0)
-            .count() as u64;
-        Ok(num_possible)
-    }
+fn part1<R: BufRead>(reader: R) -> Result<u64> {
+    let (available_patterns, requested) = timing::parse(|| parse_input(reader))?;
 
-    assert_eq!(6, part1(BufReader::new(TEST1.as_bytes()))?);
+    let num_possible = requested
+        .into_iter()
+        .map(|r| check_possible(r, available_patterns.clone()))
+        .filter(|b| *b > 0)
+        .count() as u64;
+    Ok(num_possible)
+}
 
-    let input_file = BufReader::new(File::open(INPUT_FILE)?);
-    let result = time_snippet!(part1(input_file)?);
-    println!("Result = {}", result);
-    //endregion
+fn part2<R: BufRead>(reader: R) -> Result<u64> {
+    let (available_patterns, requested) = timing::parse(|| parse_input(reader))?;
 
-    //region Part 2
-    println!("\n=== Part 2 ===");
+    let num_possible = requested
+        .into_iter()
+        .map(|r| check_possible(r, available_patterns.clone()))
+        .sum::<u64>();
+    Ok(num_possible)
+}
 
-    fn part2<R: BufRead>(reader: R) -> Result<u64> {
-        let (available_patterns, requested) = parse_input(reader)?;
+pub struct Day19;
 
-        let num_possible = requested
-            .into_iter()
-            .map(|r| check_possible(r, available_patterns.clone()))
-            .sum::<u64>();
-        Ok(num_possible)
+impl Solution for Day19 {
+    fn day(&self) -> &'static str {
+        DAY
     }
 
-    assert_eq!(16, part2(BufReader::new(TEST1.as_bytes()))?);
-
-    let input_file = BufReader::new(File::open(INPUT_FILE)?);
-    let result = time_snippet!(part2(input_file)?);
-    println!("Result = {}", result);
-    //endregion
+    fn part1(&self, reader: &mut dyn BufRead, _params: &Params) -> Result<String> {
+        Ok(part1(reader)?.to_string())
+    }
 
-    Ok(())
+    fn part2(&self, reader: &mut dyn BufRead, _params: &Params) -> Result<String> {
+        Ok(part2(reader)?.to_string())
+    }
 }
@@ -0,0 +1,179 @@
+use crate::grid::Grid;
+use crate::point::Point;
+use crate::search;
+use crate::{examples, timing, Param, ParamKind, Params, Solution};
+use anyhow::*;
+use std::io::BufRead;
+use std::vec;
+
+const DAY: &str = "20";
+
+const PARAMS: &[Param] = &[Param {
+    name: "min_save",
+    default: "100",
+    kind: ParamKind::Number,
+    help: "Minimal number of picoseconds a cheat has to save to be counted",
+}];
+
+const TEST1: &str = "\
+###############
+#...#...#.....#
+#.#.#.#.#.###.#
+#S#...#.#.#...#
+#######.#.#.###
+#######.#.#...#
+#######.#.###.#
+###..E#...#...#
+###.#######.###
+#...###...#...#
+#.#####.#.###.#
+#.#...#.#.#...#
+#.#.#.#.#.#.###
+#...#...#...###
+###############
+";
+
+examples! {
+    Day20;
+    example_part1: TEST1 => { params: { min_save: "20" }, part1: "5" },
+    example_part2: TEST1 => { params: { min_save: "76" }, part2: "3" },
+}
+
+fn parse_input<R: BufRead>(reader: R) -> Result<Grid<char>> {
+    let labyrinth = Grid::read(DAY, reader, "#.SE")?;
+    for needle in ['S', 'E'] {
+        if labyrinth.find(&needle).is_none() {
+            bail!("Day {} input has no `{}`", DAY, needle);
+        }
+    }
+    Ok(labyrinth)
+}
+
+fn calculate_dist_to_end(labyrinth: &Grid<char>) -> Grid<Option<u32>> {
+    let end_point = labyrinth.find(&'E').unwrap();
+
+    let paths = search::bfs([end_point], |&pos| {
+        labyrinth
+            .neighbours4(pos)
+            .filter(|&n| labyrinth[n] != '#')
+            .collect::<Vec<_>>()
+    });
+
+    let mut distances = labyrinth.map(|_| None);
+    for (&p, &distance) in paths.distances() {
+        distances[p] = Some(distance as u32);
+    }
+    distances
+}
+
+struct Cheat {
+    _start_point: Point,
+    _end_point: Point,
+    saved_secs: u32,
+}
+
+fn calculate_cheats(distances: &Grid<Option<u32>>, max_dist: u32) -> Vec<Cheat> {
+    let mut cheats = vec![];
+
+    let signed_dist = max_dist as i64;
+
+    for (start, start_distance) in distances.iter() {
+        let Some(start_distance) = start_distance else {
+            continue;
+        };
+        for xd in -signed_dist..=signed_dist {
+            for yd in -signed_dist..=signed_dist {
+                let cheat_time = (xd.abs() + yd.abs()) as u32;
+                if cheat_time > max_dist {
+                    continue;
+                }
+
+                let end = Point::new(start.row + xd, start.col + yd);
+
+                if let Some(Some(end_distance)) = distances.get(end) {
+                    let saved_secs = start_distance
+                        .saturating_sub(*end_distance)
+                        .saturating_sub(cheat_time);
+                    if saved_secs == 0 {
+                        continue;
+                    }
+                    let cheat = Cheat {
+                        _start_point: start,
+                        _end_point: end,
+                        saved_secs,
+                    };
+                    cheats.push(cheat);
+                }
+            }
+        }
+    }
+
+    cheats
+}
+
+fn part1<R: BufRead>(reader: R, minimal_save_dist: u32) -> Result<u64> {
+    let labyrinth = timing::parse(|| parse_input(reader))?;
+
+    let distances = calculate_dist_to_end(&labyrinth);
+
+    let cheats = calculate_cheats(&distances, 2);
+
+    Ok(cheats
+        .into_iter()
+        .filter(|c| c.saved_secs >= minimal_save_dist)
+        .count() as u64)
+}
+
+fn part2<R: BufRead>(reader: R, minimal_save_dist: u32) -> Result<u64> {
+    let labyrinth = timing::parse(|| parse_input(reader))?;
+
+    let distances = calculate_dist_to_end(&labyrinth);
+
+    let cheats = calculate_cheats(&distances, 20);
+
+    Ok(cheats
+        .into_iter()
+        .filter(|c| c.saved_secs >= minimal_save_dist)
+        .count() as u64)
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    fn day(&self) -> &'static str {
+        DAY
+    }
+
+    fn params(&self) -> &'static [Param] {
+        PARAMS
+    }
+
+    fn part1(&self, reader: &mut dyn BufRead, params: &Params) -> Result<String> {
+        Ok(part1(reader, params.get("min_save")?)?.to_string())
+    }
+
+    fn part2(&self, reader: &mut dyn BufRead, params: &Params) -> Result<String> {
+        Ok(part2(reader, params.get("min_save")?)?.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_examples() -> Result<()> {
+        assert_eq!(8, part1(TEST1.as_bytes(), 12)?);
+        assert_eq!(5, part1(TEST1.as_bytes(), 19)?);
+        assert_eq!(5, part1(TEST1.as_bytes(), 20)?);
+        assert_eq!(4, part1(TEST1.as_bytes(), 21)?);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_examples() -> Result<()> {
+        assert_eq!(29, part2(TEST1.as_bytes(), 72)?);
+        assert_eq!(3, part2(TEST1.as_bytes(), 76)?);
+        Ok(())
+    }
+}
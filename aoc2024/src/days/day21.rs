@@ -0,0 +1,481 @@
+use self::ActionField::{Enter, Key, Movement, Num};
+use crate::grid::Grid;
+use crate::point::Direction::{self, Down, Left, Right, Up};
+use crate::point::Point;
+use crate::search;
+use crate::{debug, examples, lines, timing, Param, ParamKind, Params, Solution};
+use anyhow::*;
+use itertools::Itertools;
+use std::collections::HashMap;
+use std::io::BufRead;
+use std::result::Result::Ok;
+
+const DAY: &str = "21";
+
+const PARAMS: &[Param] = &[
+    Param {
+        name: "part1_robots",
+        default: "2",
+        kind: ParamKind::Number,
+        help: "Robots on directional keypads between you and the door in part 1",
+    },
+    Param {
+        name: "part2_robots",
+        default: "25",
+        kind: ParamKind::Number,
+        help: "Robots on directional keypads between you and the door in part 2",
+    },
+];
+
+const TEST1: &str = "\
+029A
+980A
+179A
+456A
+379A
+";
+
+examples! {
+    Day21;
+    example1: TEST1 => {
+        part1: "126384",
+        part2: "154115708116294",
+    },
+}
+
+// Written without line continuations, which would eat the leading gaps
+const NUMERIC_KEYPAD: &str = "789\n456\n123\n 0A\n";
+
+const DIRECTIONAL_KEYPAD: &str = " ^A\n<v>\n";
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+enum ActionField {
+    Movement(Direction),
+    Num(u8),
+    Key(char),
+    Enter,
+}
+
+impl ActionField {
+    /// Reads a key label: digits, arrows, `A` for enter, any other character as itself.
+    fn from_label(c: char) -> ActionField {
+        match c {
+            'A' => Enter,
+            '0'..='9' => Num(c as u8 - b'0'),
+            '^' | 'v' | '<' | '>' => Movement(Direction::try_from(c).unwrap()),
+            _ => Key(c),
+        }
+    }
+
+    fn label(self) -> char {
+        match self {
+            Movement(dir) => dir.arrow(),
+            Num(n) => (b'0' + n) as char,
+            Key(c) => c,
+            Enter => 'A',
+        }
+    }
+}
+
+/// A keypad read from a text layout, one character per key and spaces for gaps the robot arm
+/// must never point at.
+#[derive(Clone)]
+struct Keypad {
+    layout: Grid<Option<ActionField>>,
+    keys: HashMap<ActionField, Point>,
+}
+
+/// Equally cheap routes are ordered by these moves, earlier ones first.
+const MOVE_PREFERENCE: [Direction; 4] = [Left, Down, Up, Right];
+
+impl Keypad {
+    fn parse(layout: &str) -> Result<Keypad> {
+        let width = layout.lines().map(|l| l.chars().count()).max().unwrap_or(0);
+        let rows = layout
+            .lines()
+            .map(|l| {
+                l.chars()
+                    .pad_using(width, |_| ' ')
+                    .map(|c| (c != ' ').then(|| ActionField::from_label(c)))
+                    .collect()
+            })
+            .collect();
+        let layout = Grid::from_rows(rows)?;
+
+        let mut keys = HashMap::new();
+        for (p, key) in layout.iter() {
+            if let Some(key) = key {
+                if keys.insert(*key, p).is_some() {
+                    bail!("Key `{}` appears twice on the keypad", key.label());
+                }
+            }
+        }
+        ensure!(keys.contains_key(&Enter), "The keypad has no `A` key");
+
+        Ok(Keypad { layout, keys })
+    }
+
+    fn new_numeric_keypad() -> Keypad {
+        Keypad::parse(NUMERIC_KEYPAD).unwrap()
+    }
+
+    fn new_directional_keypad() -> Keypad {
+        Keypad::parse(DIRECTIONAL_KEYPAD).unwrap()
+    }
+
+    /// All shortest routes from `from` to `to` that stay off the gaps, most preferred first.
+    fn routes(&self, from: ActionField, to: ActionField) -> Vec<Vec<Direction>> {
+        let (Some(&start), Some(&end)) = (self.keys.get(&from), self.keys.get(&to)) else {
+            return vec![];
+        };
+        let paths = search::bfs([start], |&p| {
+            self.layout
+                .neighbours4(p)
+                .filter(|&n| self.layout[n].is_some())
+                .collect::<Vec<_>>()
+        });
+
+        paths
+            .all_paths_to(&end)
+            .into_iter()
+            .map(|points| {
+                points
+                    .iter()
+                    .tuple_windows()
+                    .map(|(&a, &b)| *Direction::ALL.iter().find(|&&d| a.step(d) == b).unwrap())
+                    .collect::<Vec<_>>()
+            })
+            .sorted_by_key(|route| {
+                let turns = route.iter().tuple_windows().filter(|(a, b)| a != b).count();
+                let order: Vec<_> = route
+                    .iter()
+                    .map(|d| MOVE_PREFERENCE.iter().position(|p| p == d))
+                    .collect();
+                (turns, order)
+            })
+            .collect()
+    }
+}
+
+/// A chain of keypads, each one typed on by a robot that is steered from the next keypad. You type
+/// on the last one yourself.
+struct KeypadChain {
+    keypads: Vec<Keypad>,
+    pair_presses: HashMap<(usize, ActionField, ActionField), u64>,
+}
+
+impl KeypadChain {
+    fn new(door: Keypad, directional: Keypad, robots: usize) -> Result<KeypadChain> {
+        for dir in Direction::ALL {
+            ensure!(
+                directional.keys.contains_key(&Movement(dir)),
+                "The directional keypad has no `{}` key",
+                dir
+            );
+        }
+        let mut keypads = vec![door];
+        keypads.extend(std::iter::repeat_n(directional, robots + 1));
+        Ok(KeypadChain {
+            keypads,
+            pair_presses: HashMap::new(),
+        })
+    }
+
+    /// The cheapest route on keypad `level` from `from` to `to` and its cost in presses of yours,
+    /// including the final press of `to`.
+    fn best_route(
+        &mut self,
+        level: usize,
+        from: ActionField,
+        to: ActionField,
+    ) -> Option<(u64, Vec<Direction>)> {
+        self.keypads[level]
+            .routes(from, to)
+            .into_iter()
+            .map(|route| (self.presses(level + 1, &with_enter(&route)), route))
+            .min_by_key(|(presses, _)| *presses)
+    }
+
+    fn find_optimal_ordering(
+        &mut self,
+        level: usize,
+        from: ActionField,
+        to: ActionField,
+    ) -> Vec<Direction> {
+        self.best_route(level, from, to).unwrap().1
+    }
+
+    /// Your presses that make the robot at keypad `level`, starting at `A`, type `keys`.
+    fn presses(&mut self, level: usize, keys: &[ActionField]) -> u64 {
+        if level == self.keypads.len() - 1 {
+            return keys.len() as u64;
+        }
+        // u64::MAX stands for keys that cannot be reached, and stays that way when summed up
+        let mut presses: u64 = 0;
+        for (&from, &to) in [Enter].iter().chain(keys).tuple_windows() {
+            let pair_presses = match self.pair_presses.get(&(level, from, to)) {
+                Some(&known) => known,
+                None => {
+                    let (known, _) = self
+                        .best_route(level, from, to)
+                        .unwrap_or((u64::MAX, vec![]));
+                    self.pair_presses.insert((level, from, to), known);
+                    known
+                }
+            };
+            presses = presses.saturating_add(pair_presses);
+        }
+        presses
+    }
+
+    /// The button sequence of yours that makes the robot at keypad `level` type `keys`. It grows
+    /// exponentially with the length of the chain.
+    fn sequence(&mut self, level: usize, keys: &[ActionField]) -> Vec<ActionField> {
+        if level == self.keypads.len() - 1 {
+            return keys.to_vec();
+        }
+        let mut sequence = vec![];
+        for (&from, &to) in [Enter].iter().chain(keys).tuple_windows() {
+            let route = self.find_optimal_ordering(level, from, to);
+            sequence.extend(self.sequence(level + 1, &with_enter(&route)));
+        }
+        sequence
+    }
+}
+
+fn with_enter(route: &[Direction]) -> Vec<ActionField> {
+    route.iter().map(|&d| Movement(d)).chain([Enter]).collect()
+}
+
+/// Reads the door codes, each as its numeric part and the buttons to press on the numeric keypad.
+fn parse_input<R: BufRead>(reader: R) -> Result<Vec<(u64, Vec<ActionField>)>> {
+    lines(DAY, reader)
+        .map(|line| {
+            let line = line?;
+            let Some(digits) = line.text.strip_suffix('A') else {
+                return Err(line.error_at_end("expected the code to end with `A`"));
+            };
+            if let Some(column) = digits.find(|c: char| !c.is_ascii_digit()) {
+                return Err(line.error(column + 1, "expected a digit"));
+            }
+            let numeric_prefix = line.parse::<u64>(digits)?;
+
+            let numeric_input = digits
+                .bytes()
+                .map(|x| Num(x - b'0'))
+                .chain([Enter])
+                .collect();
+            Ok((numeric_prefix, numeric_input))
+        })
+        .collect()
+}
+
+/// Renders buttons the way the puzzle writes them, e.g. `<A^A>^^AvvvA`.
+fn render(actions: &[ActionField]) -> String {
+    actions.iter().map(|action| action.label()).collect()
+}
+
+/// Above this many robots the button sequences are too long to be worth printing.
+const MAX_PRINTED_ROBOTS: usize = 3;
+
+fn solve<R: BufRead>(reader: R, robots: usize) -> Result<u64> {
+    let inputs = timing::parse(|| parse_input(reader))?;
+
+    let mut chain = KeypadChain::new(
+        Keypad::new_numeric_keypad(),
+        Keypad::new_directional_keypad(),
+        robots,
+    )?;
+
+    let mut result = 0;
+    for (numeric_prefix, numeric_input) in inputs {
+        let presses = chain.presses(0, &numeric_input);
+        ensure!(
+            presses != u64::MAX,
+            "Code {} cannot be typed on the keypad",
+            render(&numeric_input)
+        );
+        if robots <= MAX_PRINTED_ROBOTS {
+            debug!(
+                "{}: {}",
+                render(&numeric_input),
+                render(&chain.sequence(0, &numeric_input))
+            );
+        }
+        result += numeric_prefix * presses;
+    }
+
+    Ok(result)
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    fn day(&self) -> &'static str {
+        DAY
+    }
+
+    fn params(&self) -> &'static [Param] {
+        PARAMS
+    }
+
+    fn part1(&self, reader: &mut dyn BufRead, params: &Params) -> Result<String> {
+        Ok(solve(reader, params.get("part1_robots")?)?.to_string())
+    }
+
+    fn part2(&self, reader: &mut dyn BufRead, params: &Params) -> Result<String> {
+        Ok(solve(reader, params.get("part2_robots")?)?.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solve_examples() -> Result<()> {
+        assert_eq!(126384, solve(TEST1.as_bytes(), 2)?);
+        assert_eq!(1881090, solve(TEST1.as_bytes(), 5)?);
+        Ok(())
+    }
+
+    fn input_to_actions(s: String) -> Vec<ActionField> {
+        s.chars().map(ActionField::from_label).collect()
+    }
+
+    /// The chain of part 1, with the door keypad at level 0 and the first directional one at 1.
+    fn example_chain() -> KeypadChain {
+        KeypadChain::new(
+            Keypad::new_numeric_keypad(),
+            Keypad::new_directional_keypad(),
+            2,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn closed_form_matches_the_built_sequences() -> Result<()> {
+        for robots in 0..=3 {
+            let mut chain = KeypadChain::new(
+                Keypad::new_numeric_keypad(),
+                Keypad::new_directional_keypad(),
+                robots,
+            )?;
+            for (_, code) in parse_input(TEST1.as_bytes())? {
+                let sequence = chain.sequence(0, &code);
+                assert_eq!(sequence.len() as u64, chain.presses(0, &code));
+            }
+        }
+
+        let puzzle_sequence = input_to_actions(
+            "<vA<AA>>^AvAA<^A>A<v<A>>^AvA^A<vA>^A<v<A>^A>AAvA^A<v<A>A>^AAAvA<^A>A".to_string(),
+        );
+        let code = [Num(0), Num(2), Num(9), Enter];
+        assert_eq!(
+            puzzle_sequence.len() as u64,
+            example_chain().presses(0, &code)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_numeric_keyboard_optimal_pathing_upleft_edgecase() {
+        let mut chain = example_chain();
+        let res = chain.find_optimal_ordering(0, Num(0), Num(7));
+        assert_eq!(res, vec![Up, Up, Up, Left])
+    }
+
+    #[test]
+    fn test_numeric_keyboard_optimal_pathing_rightdown_edgecase() {
+        let mut chain = example_chain();
+        let res = chain.find_optimal_ordering(0, Num(7), Num(0));
+        assert_eq!(res, vec![Right, Down, Down, Down]);
+    }
+
+    #[test]
+    fn test_numeric_keyboard_big_field_3_7() {
+        let mut chain = example_chain();
+        let res = chain.find_optimal_ordering(0, Num(3), Num(7));
+        assert_eq!(res, vec![Left, Left, Up, Up]);
+    }
+
+    #[test]
+    fn test_numeric_keyboard_big_field_7_3() {
+        let mut chain = example_chain();
+        let res = chain.find_optimal_ordering(0, Num(7), Num(3));
+        assert_eq!(res, vec![Down, Down, Right, Right]);
+    }
+
+    #[test]
+    fn test_numeric_keyboard_big_field_9_1() {
+        let mut chain = example_chain();
+        let res = chain.find_optimal_ordering(0, Num(9), Num(1));
+        assert_eq!(res, vec![Left, Left, Down, Down]);
+    }
+
+    #[test]
+    fn test_numeric_keyboard_big_field_9_3() {
+        let mut chain = example_chain();
+        let res = chain.find_optimal_ordering(0, Num(9), Num(3));
+        assert_eq!(res, vec![Down, Down]);
+    }
+
+    #[test]
+    fn test_numeric_keyboard_big_field_0_2() {
+        let mut chain = example_chain();
+        let res = chain.find_optimal_ordering(0, Num(0), Num(2));
+        assert_eq!(res, vec![Up]);
+    }
+
+    #[test]
+    fn test_directional_enter_left_optimal_solved() {
+        let mut chain = example_chain();
+        let res = chain.find_optimal_ordering(1, Enter, Movement(Left));
+        assert_eq!(res, vec![Down, Left, Left]);
+    }
+
+    #[test]
+    fn test_directional_left_enter_optimal_solved() {
+        let mut chain = example_chain();
+        let res = chain.find_optimal_ordering(1, Movement(Left), Enter);
+        assert_eq!(res, vec![Right, Right, Up]);
+    }
+
+    #[test]
+    fn test_directional_enter_down_optimal_solved() {
+        let mut chain = example_chain();
+        let res = chain.find_optimal_ordering(1, Enter, Movement(Down));
+        assert_eq!(res, vec![Left, Down]);
+    }
+
+    #[test]
+    fn test_directional_down_enter_optimal_solved() {
+        let mut chain = example_chain();
+        let res = chain.find_optimal_ordering(1, Movement(Down), Enter);
+        assert_eq!(res, vec![Up, Right]);
+    }
+
+    #[test]
+    fn custom_layouts_route_around_gaps() -> Result<()> {
+        let keypad = Keypad::parse("123\n4 5\n67A\n")?;
+        let routes = keypad.routes(Num(4), Num(5));
+        assert_eq!(
+            vec![vec![Down, Right, Right, Up], vec![Up, Right, Right, Down]],
+            routes
+        );
+
+        let door = Keypad::parse("XSU\nA  \n")?;
+        let mut chain = KeypadChain::new(door, Keypad::new_directional_keypad(), 0)?;
+        let code = input_to_actions("UA".to_string());
+        assert_eq!("^>>A<<vA", render(&chain.sequence(0, &code)));
+        assert_eq!(8, chain.presses(0, &code));
+
+        assert!(Keypad::parse("12\n21A\n").is_err());
+        assert!(KeypadChain::new(door_without_arrows()?, door_without_arrows()?, 1).is_err());
+        Ok(())
+    }
+
+    fn door_without_arrows() -> Result<Keypad> {
+        Keypad::parse("12\n3A\n")
+    }
+}
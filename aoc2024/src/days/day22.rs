@@ -0,0 +1,171 @@
+use crate::{examples, lines, timing, Params, Solution};
+use anyhow::*;
+use std::collections::HashMap;
+use std::io::BufRead;
+
+const DAY: &str = "22";
+
+const TEST1: &str = "\
+1
+10
+100
+2024
+";
+
+const TEST2: &str = "\
+1
+2
+3
+2024
+";
+
+examples! {
+    Day22;
+    example1: TEST1 => { part1: "37327623" },
+    example2: TEST2 => { part2: "23" },
+}
+
+struct SecretIterator {
+    last_secret: u64,
+}
+
+impl Iterator for SecretIterator {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut cur_secret = self.last_secret;
+
+        // Calculate the result of multiplying the secret number by 64.
+        // Then, mix this result into the secret number. Finally, prune the secret number.
+        cur_secret = Self::prune(Self::mix(cur_secret, cur_secret * 64));
+
+        // Calculate the result of dividing the secret number by 32.
+        // Round the result down to the nearest integer.
+        // Then, mix this result into the secret number. Finally, prune the secret number.
+        cur_secret = Self::prune(Self::mix(cur_secret, cur_secret / 32));
+
+        // Calculate the result of multiplying the secret number by 2048.
+        // Then, mix this result into the secret number. Finally, prune the secret number.
+        cur_secret = Self::prune(Self::mix(cur_secret, cur_secret * 2048));
+
+        self.last_secret = cur_secret;
+
+        Some(cur_secret)
+    }
+}
+
+impl SecretIterator {
+    fn mix(secret: u64, res: u64) -> u64 {
+        secret ^ res
+    }
+
+    fn prune(secret: u64) -> u64 {
+        secret % 16777216
+    }
+}
+
+fn parse_input<R: BufRead>(reader: R) -> Result<Vec<u64>> {
+    lines(DAY, reader)
+        .map(|line| {
+            let line = line?;
+            line.parse::<u64>(&line.text)
+        })
+        .collect()
+}
+
+fn part1<R: BufRead>(reader: R) -> Result<u64> {
+    let numbers = timing::parse(|| parse_input(reader))?;
+
+    let res = numbers
+        .iter()
+        .map(|s| SecretIterator { last_secret: *s })
+        .map(|mut it| it.nth(1999).unwrap())
+        .sum();
+
+    Ok(res)
+}
+
+fn part2<R: BufRead>(reader: R) -> Result<u32> {
+    let numbers = timing::parse(|| parse_input(reader))?;
+
+    let ape_numbers: Vec<Vec<_>> = numbers
+        .iter()
+        .map(|s| SecretIterator { last_secret: *s })
+        .map(|it| {
+            it.take(2000)
+                .map(|x| (x as u32) % 10)
+                .collect::<Vec<u32>>()
+                .windows(2)
+                .map(|nums| (nums[1] as i32 - (nums[0] as i32), nums[1]))
+                .collect()
+        })
+        .collect();
+
+    let itermaps: Vec<_> = ape_numbers
+        .iter()
+        .map(|app| {
+            let mut first_number_map = HashMap::new();
+            app.windows(4).for_each(|x| {
+                let ((a, _), (b, _), (c, _), (d, num)) = (x[0], x[1], x[2], x[3]);
+
+                let key = (a, b, c, d);
+                if !first_number_map.contains_key(&key) {
+                    first_number_map.insert((a, b, c, d), num);
+                }
+            });
+            first_number_map
+        })
+        .collect();
+
+    let mut all_first_numbs = HashMap::new();
+    for m in itermaps {
+        for (sequence, firstnum) in m.into_iter() {
+            all_first_numbs
+                .entry(sequence)
+                .or_insert(vec![])
+                .push(firstnum);
+        }
+    }
+
+    let res = all_first_numbs
+        .values()
+        .map(|x| x.iter().sum::<u32>())
+        .max()
+        .unwrap_or(0);
+
+    Ok(res)
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    fn day(&self) -> &'static str {
+        DAY
+    }
+
+    fn part1(&self, reader: &mut dyn BufRead, _params: &Params) -> Result<String> {
+        Ok(part1(reader)?.to_string())
+    }
+
+    fn part2(&self, reader: &mut dyn BufRead, _params: &Params) -> Result<String> {
+        Ok(part2(reader)?.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn iterator_next_123_first_10_correct() {
+        let iter = SecretIterator { last_secret: 123 };
+
+        assert_eq!(
+            vec![
+                15887950, 16495136, 527345, 704524, 1553684, 12683156, 11100544, 12249484, 7753432,
+                5908254
+            ],
+            iter.take(10).collect::<Vec<u64>>()
+        );
+    }
+}
@@ -1,16 +1,12 @@
-use adv_code_2024::*;
+use crate::{debug, examples, lines, timing, Params, Solution};
 use anyhow::*;
-use code_timing_macros::time_snippet;
-use const_format::concatcp;
 use itertools::Itertools;
 use std::cmp::{max, min};
 use std::collections::HashSet;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::io::BufRead;
 use std::vec;
 
 const DAY: &str = "23";
-const INPUT_FILE: &str = concatcp!("input/", DAY, ".txt");
 
 const TEST: &str = "\
 kh-tc
@@ -47,6 +43,11 @@ tb-vc
 td-yn
 ";
 
+examples! {
+    Day23;
+    example: TEST => { part1: "7", part2: "co,de,ka,ta" },
+}
+
 #[derive(Debug, Clone, Hash, Eq, PartialEq, Ord, PartialOrd)]
 struct Node(String);
 
@@ -65,15 +66,19 @@ impl Connection {
     }
 }
 
-fn parse_input<R: BufRead>(reader: R) -> HashSet<Connection> {
-    reader
-        .lines()
+fn parse_input<R: BufRead>(reader: R) -> Result<HashSet<Connection>> {
+    lines(DAY, reader)
         .map(|line| {
-            let binding = line.unwrap();
-            let (a, b) = binding.split_once('-').unwrap();
+            let line = line?;
+            let (a, b) = line.split_once("-")?;
+            for name in [a, b] {
+                if name.is_empty() || !name.chars().all(|c| c.is_ascii_lowercase()) {
+                    return Err(line.error_at(name, "expected a computer name"));
+                }
+            }
             let a = a.to_string();
             let b = b.to_string();
-            Connection::new(Node(a), Node(b))
+            Ok(Connection::new(Node(a), Node(b)))
         })
         .collect()
 }
@@ -115,68 +120,54 @@ fn construct_cliques(connections: &HashSet<Connection>) -> Vec<HashSet<Node>> {
         new_cliques.dedup();
 
         last_step_cliques = new_cliques;
-        println!("{:?}", last_step_cliques.len());
+        debug!("{:?}", last_step_cliques.len());
     }
 
     all_cliques
 }
 
-fn main() -> Result<()> {
-    start_day(DAY);
-
-    //region Part 1
-    println!("=== Part 1 ===");
-
-    fn part1<R: BufRead>(reader: R) -> Result<u64> {
-        let connections = parse_input(reader);
-
-        let all_cliques = construct_cliques(&connections);
+fn part1<R: BufRead>(reader: R) -> Result<u64> {
+    let connections = timing::parse(|| parse_input(reader))?;
 
-        let known_with_t = all_cliques
-            .iter()
-            .filter(|c| c.iter().count() == 3)
-            .filter(|s| s.iter().any(|n| n.0.starts_with("t")))
-            .count() as u64;
+    let all_cliques = construct_cliques(&connections);
 
-        Ok(known_with_t)
-    }
+    let known_with_t = all_cliques
+        .iter()
+        .filter(|c| c.iter().count() == 3)
+        .filter(|s| s.iter().any(|n| n.0.starts_with("t")))
+        .count() as u64;
 
-    assert_eq!(7, part1(BufReader::new(TEST.as_bytes()))?);
+    Ok(known_with_t)
+}
 
-    /*
-    let input_file = BufReader::new(File::open(INPUT_FILE)?);
-    let result = time_snippet!(part1(input_file)?);
-    println!("Result = {}", result);
-    */
-    //endregion
+fn part2<R: BufRead>(reader: R) -> Result<String> {
+    let connections = timing::parse(|| parse_input(reader))?;
 
-    //region Part 2
-    println!("\n=== Part 2 ===");
+    let all_cliques = construct_cliques(&connections);
 
-    fn part2<R: BufRead>(reader: R) -> Result<String> {
-        let connections = parse_input(reader);
+    let Some(biggest_clique) = all_cliques.last() else {
+        bail!("Day {} input has no connections", DAY);
+    };
 
-        let all_cliques = construct_cliques(&connections);
+    let mut nodes = biggest_clique.iter().collect_vec();
+    nodes.sort();
+    let output = nodes.into_iter().map(|n| n.0.to_string()).join(",");
 
-        let biggest_clique = all_cliques.last().unwrap();
+    Ok(output)
+}
 
-        let mut nodes = biggest_clique.iter().collect_vec();
-        nodes.sort();
-        let output = nodes.into_iter().map(|n| n.0.to_string()).join(",");
+pub struct Day23;
 
-        Ok(output)
+impl Solution for Day23 {
+    fn day(&self) -> &'static str {
+        DAY
     }
 
-    assert_eq!(
-        "co,de,ka,ta".to_string(),
-        part2(BufReader::new(TEST.as_bytes()))?
-    );
-
-    let input_file = BufReader::new(File::open(INPUT_FILE)?);
-    let result = time_snippet!(part2(input_file)?);
-    println!("Result = {}", result);
-    //endregion
-    //
+    fn part1(&self, reader: &mut dyn BufRead, _params: &Params) -> Result<String> {
+        Ok(part1(reader)?.to_string())
+    }
 
-    Ok(())
+    fn part2(&self, reader: &mut dyn BufRead, _params: &Params) -> Result<String> {
+        Ok(part2(reader)?.to_string())
+    }
 }
@@ -0,0 +1,1256 @@
+use crate::{
+    debug, examples, lines, log_enabled, timing, Export, Param, ParamKind, Params, Solution,
+    Verbosity,
+};
+use anyhow::*;
+use itertools::Itertools;
+use std::cmp::{max_by_key, min_by_key};
+use std::collections::{HashMap, HashSet};
+use std::io::BufRead;
+use std::ptr;
+use std::result::Result::Ok;
+use std::string::ToString;
+
+const DAY: &str = "24";
+
+const PARAMS: &[Param] = &[Param {
+    name: "swaps",
+    default: "4",
+    kind: ParamKind::Number,
+    help: "Number of swapped output pairs to find in part 2",
+}];
+
+const TEST1: &str = "\
+x00: 1
+x01: 0
+x02: 1
+x03: 1
+x04: 0
+y00: 1
+y01: 1
+y02: 1
+y03: 1
+y04: 1
+
+ntg XOR fgs -> mjb
+y02 OR x01 -> tnw
+kwq OR kpj -> z05
+x00 OR x03 -> fst
+tgd XOR rvg -> z01
+vdt OR tnw -> bfw
+bfw AND frj -> z10
+ffh OR nrd -> bqk
+y00 AND y03 -> djm
+y03 OR y00 -> psh
+bqk OR frj -> z08
+tnw OR fst -> frj
+gnj AND tgd -> z11
+bfw XOR mjb -> z00
+x03 OR x00 -> vdt
+gnj AND wpb -> z02
+x04 AND y00 -> kjc
+djm OR pbm -> qhw
+nrd AND vdt -> hwm
+kjc AND fst -> rvg
+y04 OR y02 -> fgs
+y01 AND x02 -> pbm
+ntg OR kjc -> kwq
+psh XOR fgs -> tgd
+qhw XOR tgd -> z09
+pbm OR djm -> kpj
+x03 XOR y03 -> ffh
+x00 XOR y04 -> ntg
+bfw OR bqk -> z06
+nrd XOR fgs -> wpb
+frj XOR qhw -> z04
+bqk OR frj -> z07
+y03 OR x01 -> nrd
+hwm AND bqk -> z03
+tgd XOR rvg -> z12
+tnw OR pbm -> gnj
+";
+
+examples! {
+    Day24;
+    example1: TEST1 => { part1: "2024" },
+}
+
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Debug)]
+struct Var {
+    name: String,
+}
+
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum BinOp {
+    AND,
+    OR,
+    XOR,
+}
+
+impl BinOp {
+    fn call(&self, left: u8, right: u8) -> u8 {
+        match self {
+            BinOp::AND => left & right,
+            BinOp::OR => left | right,
+            BinOp::XOR => left ^ right,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct BoolExpr {
+    left: Var,
+    right: Var,
+    target: Var,
+    op: BinOp,
+}
+
+fn parse_input<R: BufRead>(reader: R) -> Result<(Vec<BoolExpr>, HashMap<Var, u8>)> {
+    let mut vars = vec![];
+    let mut known_vals = HashMap::new();
+
+    let var_regex = regex::Regex::new(r"^(\w+): ([01])$")?;
+    let calc_regex = regex::Regex::new(r"^(\w+) (.+) (\w+) -> (\w+)$")?;
+
+    for line in lines(DAY, reader) {
+        let line = line?;
+
+        if let Some(capt) = var_regex.captures(&line.text) {
+            let name = capt[1].to_string();
+            let num = line.parse::<u8>(&capt[2])?;
+
+            known_vals.insert(Var { name }, num);
+        } else if let Some(capt) = calc_regex.captures(&line.text) {
+            let left_orig = Var {
+                name: capt[1].to_string(),
+            };
+            let right_orig = Var {
+                name: capt[3].to_string(),
+            };
+            let target = Var {
+                name: capt[4].to_string(),
+            };
+
+            let left = min_by_key(left_orig.clone(), right_orig.clone(), |x| x.name.clone());
+            let right = max_by_key(left_orig, right_orig, |x| x.name.clone());
+
+            let op = match &capt[2] {
+                "AND" => BinOp::AND,
+                "OR" => BinOp::OR,
+                "XOR" => BinOp::XOR,
+                other => return Err(line.error_at(other, "expected `AND`, `OR` or `XOR`")),
+            };
+
+            vars.push(BoolExpr {
+                left,
+                right,
+                target,
+                op,
+            });
+        } else if !line.text.is_empty() {
+            return Err(line.error(1, "expected `wire: 0|1` or `a OP b -> wire`"));
+        }
+    }
+
+    Ok((vars, known_vals))
+}
+
+/// The gate network compiled into a dependency graph, with the gates in an order in which every
+/// gate comes after the gates driving its inputs. Wires are numbered, so the circuit can be
+/// evaluated many times without hashing names.
+struct Circuit {
+    wires: Vec<Var>,
+    index: HashMap<Var, usize>,
+    /// `(left, op, right, target)` in topological order
+    gates: Vec<(usize, BinOp, usize, usize)>,
+    /// Wires that no gate drives, which need a value from outside
+    inputs: Vec<usize>,
+    /// The `zNN` wires, lowest bit first
+    outputs: Vec<usize>,
+}
+
+impl Circuit {
+    fn compile(exprs: &[BoolExpr]) -> Result<Circuit> {
+        let mut wires = vec![];
+        let mut index = HashMap::new();
+        let mut wire = |var: &Var| {
+            *index.entry(var.clone()).or_insert_with(|| {
+                wires.push(var.clone());
+                wires.len() - 1
+            })
+        };
+        let gates: Vec<_> = exprs
+            .iter()
+            .map(|e| (wire(&e.left), e.op, wire(&e.right), wire(&e.target)))
+            .collect();
+
+        let mut driver = vec![None; wires.len()];
+        for (g, &(_, _, _, target)) in gates.iter().enumerate() {
+            if driver[target].replace(g).is_some() {
+                bail!(
+                    "Wire `{}` is driven by more than one gate",
+                    wires[target].name
+                );
+            }
+        }
+
+        // Kahn's algorithm: a gate is ready once the gates driving its inputs are done
+        let mut waiting_for = vec![0; gates.len()];
+        let mut dependents = vec![vec![]; wires.len()];
+        for (g, &(left, _, right, _)) in gates.iter().enumerate() {
+            for input in [left, right] {
+                if driver[input].is_some() {
+                    waiting_for[g] += 1;
+                    dependents[input].push(g);
+                }
+            }
+        }
+        let mut ready: Vec<_> = (0..gates.len()).filter(|&g| waiting_for[g] == 0).collect();
+        let mut order = vec![];
+        while let Some(g) = ready.pop() {
+            order.push(gates[g]);
+            for &dependent in &dependents[gates[g].3] {
+                waiting_for[dependent] -= 1;
+                if waiting_for[dependent] == 0 {
+                    ready.push(dependent);
+                }
+            }
+        }
+        if order.len() < gates.len() {
+            // drop the stuck gates that only wait for the cycle without being part of it
+            let mut stuck: Vec<_> = (0..gates.len()).filter(|&g| waiting_for[g] > 0).collect();
+            loop {
+                let before = stuck.len();
+                let still_stuck = stuck.clone();
+                stuck.retain(|&g| {
+                    dependents[gates[g].3]
+                        .iter()
+                        .any(|d| still_stuck.contains(d))
+                });
+                if stuck.len() == before {
+                    break;
+                }
+            }
+            let cycle = stuck
+                .into_iter()
+                .map(|g| &wires[gates[g].3].name)
+                .sorted()
+                .join(", ");
+            bail!("The gates form a cycle through the wires {}", cycle);
+        }
+
+        let inputs = (0..wires.len()).filter(|&w| driver[w].is_none()).collect();
+        let outputs = (0..wires.len())
+            .filter(|&w| wires[w].is_output())
+            .map(|w| Ok((wires[w].name[1..].parse::<u32>()?, w)))
+            .collect::<Result<Vec<_>>>()
+            .context("Output wires have to be named `z` and a bit number")?
+            .into_iter()
+            .sorted()
+            .map(|(_, w)| w)
+            .collect();
+
+        Ok(Circuit {
+            wires,
+            index,
+            gates: order,
+            inputs,
+            outputs,
+        })
+    }
+
+    /// The values of all wires, given the values of the input wires.
+    fn evaluate(&self, values: &HashMap<Var, u8>) -> Result<Vec<u8>> {
+        let mut wire_values = vec![0; self.wires.len()];
+        for &w in &self.inputs {
+            let Some(&value) = values.get(&self.wires[w]) else {
+                bail!(
+                    "Wire `{}` is neither driven by a gate nor given a value",
+                    self.wires[w].name
+                );
+            };
+            wire_values[w] = value;
+        }
+        for var in values.keys() {
+            if let Some(&w) = self.index.get(var) {
+                ensure!(
+                    self.inputs.contains(&w),
+                    "Wire `{}` is driven by a gate and also given a value",
+                    var.name
+                );
+            }
+        }
+        self.propagate(&mut wire_values);
+        Ok(wire_values)
+    }
+
+    fn propagate(&self, wire_values: &mut [u8]) {
+        for &(left, op, right, target) in &self.gates {
+            wire_values[target] = op.call(wire_values[left], wire_values[right]);
+        }
+    }
+
+    /// The number on the `z` wires.
+    fn output(&self, wire_values: &[u8]) -> u128 {
+        self.outputs
+            .iter()
+            .rev()
+            .fold(0, |z, &w| z << 1 | wire_values[w] as u128)
+    }
+
+    /// Runs the circuit as an adder of `x` and `y`, which go onto the `xNN` and `yNN` wires.
+    fn add(&self, x: u128, y: u128) -> u128 {
+        let mut wire_values = vec![0; self.wires.len()];
+        for &w in &self.inputs {
+            let name = &self.wires[w].name;
+            let number = match &name[..1] {
+                "x" => x,
+                "y" => y,
+                _ => continue,
+            };
+            if let Ok(bit) = name[1..].parse::<u32>() {
+                wire_values[w] = number.checked_shr(bit).unwrap_or(0) as u8 & 1;
+            }
+        }
+        self.propagate(&mut wire_values);
+        self.output(&wire_values)
+    }
+}
+
+fn part1<R: BufRead>(reader: R) -> Result<u128> {
+    let (vars, known_vals) = timing::parse(|| parse_input(reader))?;
+    let circuit = Circuit::compile(&vars)?;
+    let wire_values = circuit.evaluate(&known_vals)?;
+    Ok(circuit.output(&wire_values))
+}
+
+/// A gate whose output does not fit the ripple-carry adder the circuit is meant to be.
+#[derive(Debug, Clone, PartialEq)]
+struct Violation {
+    wire: Var,
+    reason: &'static str,
+    /// The wire it has to trade places with, when the bit it sits in tells.
+    in_place_of: Option<Var>,
+}
+
+impl Var {
+    fn is_input(&self) -> bool {
+        self.name.starts_with('x') || self.name.starts_with('y')
+    }
+
+    fn is_output(&self) -> bool {
+        self.name.starts_with('z')
+    }
+}
+
+/// The number of bits of each of the x and y inputs.
+fn adder_width(exprs: &[BoolExpr]) -> usize {
+    exprs
+        .iter()
+        .flat_map(|e| [&e.left, &e.right])
+        .filter(|v| v.name.starts_with('x'))
+        .unique()
+        .count()
+}
+
+/// Checks every gate against the pattern of a ripple-carry adder: bit 0 is a half adder
+/// (`z00 = x00 ^ y00`, carry `x00 & y00`), every other bit a full adder
+/// (`s = x ^ y`, `z = s ^ carry`, next carry `(x & y) | (s & carry)`), and the last carry is the
+/// top output bit. Works for any width. The rules look at one gate at a time, [`verify_bits`]
+/// adds the checks across bits.
+fn verify_adder(exprs: &[BoolExpr]) -> Vec<Violation> {
+    let bits = adder_width(exprs);
+    let last_output = format!("z{:0>2}", bits);
+
+    let mut consumers: HashMap<&Var, Vec<&BinOp>> = HashMap::new();
+    for e in exprs {
+        consumers.entry(&e.left).or_default().push(&e.op);
+        consumers.entry(&e.right).or_default().push(&e.op);
+    }
+    let feeds = |wire: &Var, op: BinOp| consumers.get(wire).is_some_and(|ops| ops.contains(&&op));
+
+    let mut violations = vec![];
+    for e in exprs {
+        let from_inputs = e.left.is_input() && e.right.is_input();
+        let first_bit = from_inputs && e.left.name[1..] == *"00";
+        let mut violation = |reason| {
+            violations.push(Violation {
+                wire: e.target.clone(),
+                reason,
+                in_place_of: None,
+            })
+        };
+
+        if e.target.name == last_output {
+            if bits > 1 && e.op != BinOp::OR {
+                violation("drives the top output bit, which has to be the last carry OR");
+            }
+            continue;
+        }
+        if e.target.is_output() && e.op != BinOp::XOR {
+            violation("drives an output bit, which has to be a sum XOR");
+            continue;
+        }
+
+        match e.op {
+            BinOp::XOR if first_bit => {
+                if e.target.name != "z00" {
+                    violation("is the sum of bit 0, which has to be z00");
+                }
+            }
+            BinOp::XOR if from_inputs => {
+                if !feeds(&e.target, BinOp::XOR) {
+                    violation("is a half sum of x and y, which has to feed the sum XOR");
+                }
+            }
+            BinOp::XOR => {
+                if !e.target.is_output() {
+                    violation("adds the carry to a half sum, which has to drive an output bit");
+                }
+            }
+            BinOp::AND if first_bit => {
+                if bits > 1 && !(feeds(&e.target, BinOp::XOR) && feeds(&e.target, BinOp::AND)) {
+                    violation("is the carry of bit 0, which has to feed bit 1");
+                }
+            }
+            BinOp::AND => {
+                if !feeds(&e.target, BinOp::OR) {
+                    violation("is part of a carry, which has to feed the carry OR");
+                }
+            }
+            BinOp::OR => {
+                if !(feeds(&e.target, BinOp::XOR) && feeds(&e.target, BinOp::AND)) {
+                    violation("is a carry, which has to feed the next bit");
+                }
+            }
+        }
+    }
+    violations.extend(verify_bits(exprs));
+    violations
+}
+
+/// Follows the adder bit by bit, starting from the gates on x and y, which a swap cannot move.
+/// The half sum and the carry into each bit have to meet in the XOR that drives its output bit,
+/// and the carry OR has to feed the next bit. This catches swaps between wires of the same kind at
+/// different bits, which look right gate by gate.
+fn verify_bits(exprs: &[BoolExpr]) -> Vec<Violation> {
+    let bits = adder_width(exprs);
+    let wire = |prefix: char, i: usize| Var {
+        name: format!("{}{:0>2}", prefix, i),
+    };
+    let gate = |op: BinOp, a: &Var, b: &Var| {
+        exprs.iter().find(|e| {
+            e.op == op && ((e.left == *a && e.right == *b) || (e.left == *b && e.right == *a))
+        })
+    };
+    let fed_by = |op: BinOp, input: &Var| {
+        exprs
+            .iter()
+            .find(|e| e.op == op && (e.left == *input || e.right == *input))
+    };
+    let driver = |target: &Var| exprs.iter().find(|e| e.target == *target);
+    // the carry that bit `i` actually reads: the input of its sum XOR that an OR drives
+    let carry_read_by = |i: usize| {
+        if i == bits {
+            return Some(wire('z', bits));
+        }
+        let sum = driver(&wire('z', i)).filter(|e| e.op == BinOp::XOR)?;
+        [&sum.left, &sum.right]
+            .into_iter()
+            .find(|input| driver(input).is_some_and(|e| e.op == BinOp::OR))
+            .cloned()
+    };
+
+    let mut violations = vec![];
+    let mut violation = |wire: &Var, reason, in_place_of: Option<&Var>| {
+        violations.push(Violation {
+            wire: wire.clone(),
+            reason,
+            in_place_of: in_place_of.cloned(),
+        })
+    };
+
+    let mut sums = vec![];
+    let mut carry = gate(BinOp::AND, &wire('x', 0), &wire('y', 0)).map(|e| e.target.clone());
+    for i in 1..bits {
+        let (x, y, z) = (wire('x', i), wire('y', i), wire('z', i));
+        let (Some(half), Some(and)) = (gate(BinOp::XOR, &x, &y), gate(BinOp::AND, &x, &y)) else {
+            carry = None;
+            continue;
+        };
+
+        let by_half = fed_by(BinOp::XOR, &half.target);
+        let by_carry = carry.as_ref().and_then(|c| fed_by(BinOp::XOR, c));
+        // the output bit's own XOR, unless the output bit was swapped away from a sum
+        let own = driver(&z).filter(|e| e.op == BinOp::XOR && !e.left.is_input());
+        let sum = match (by_half, by_carry) {
+            (Some(s), Some(t)) if ptr::eq(s, t) => Some(s),
+            // the half sum and the carry disagree: a sum XOR taken by an earlier bit belongs
+            // there, and the one leading to the output bit wins over the one reading the half
+            // sum, which comes straight from the inputs
+            _ => {
+                let candidates: Vec<_> = [by_half, by_carry, own]
+                    .into_iter()
+                    .flatten()
+                    .filter(|e| !sums.iter().any(|s| ptr::eq(*s, *e)))
+                    .collect();
+                candidates
+                    .iter()
+                    .find(|e| e.target == z)
+                    .or(candidates.first())
+                    .copied()
+            }
+        };
+        let Some(sum) = sum else {
+            carry = None;
+            continue;
+        };
+        sums.push(sum);
+        let reads = |wire: &Var| sum.left == *wire || sum.right == *wire;
+        let other = |wire: &Var| {
+            if sum.left == *wire {
+                &sum.right
+            } else {
+                &sum.left
+            }
+        };
+        // the inputs of the sum meant for the half sum and for the carry
+        let (half_slot, carry_slot) = match &carry {
+            _ if reads(&half.target) => (&half.target, other(&half.target)),
+            Some(carry) if reads(carry) => (other(carry), carry),
+            _ if driver(&sum.left).is_some_and(|e| e.op == BinOp::XOR && e.left.is_input()) => {
+                (&sum.left, &sum.right)
+            }
+            _ => (&sum.right, &sum.left),
+        };
+        if !reads(&half.target) {
+            violation(
+                &half.target,
+                "is a half sum, which has to feed the sum XOR of its bit",
+                Some(half_slot),
+            );
+        }
+        if let Some(carry) = carry.as_ref().filter(|c| !reads(c)) {
+            violation(
+                carry,
+                "is a carry, which has to feed the sum XOR of the next bit",
+                Some(carry_slot),
+            );
+        }
+        if sum.target != z {
+            violation(
+                &sum.target,
+                "is the sum of a bit, which has to drive its output bit",
+                Some(&z),
+            );
+        }
+
+        // the carry out of this bit is the OR of x & y and of the two inputs of the sum XOR
+        let by_and = fed_by(BinOp::OR, &and.target);
+        let carry_and = gate(BinOp::AND, &sum.left, &sum.right);
+        let by_carry_and = carry_and.and_then(|e| fed_by(BinOp::OR, &e.target));
+        carry = match (by_and, by_carry_and) {
+            (Some(o), Some(p)) if o.target == p.target => Some(o.target.clone()),
+            (o, p) => {
+                let read = carry_read_by(i + 1);
+                // the other input of the OR that the carry out is read from
+                let slot = |or: &BoolExpr, input: &Var| {
+                    if or.left == *input {
+                        or.right.clone()
+                    } else {
+                        or.left.clone()
+                    }
+                };
+                match (o, p, carry_and) {
+                    (Some(o), _, Some(carry_and)) if Some(&o.target) == read.as_ref() => violation(
+                        &carry_and.target,
+                        "is part of a carry, which has to feed its OR",
+                        Some(&slot(o, &and.target)),
+                    ),
+                    (_, Some(p), _) if Some(&p.target) == read.as_ref() => {
+                        let carry_and = carry_and.map(|e| &e.target);
+                        violation(
+                            &and.target,
+                            "is part of a carry, which has to feed its OR",
+                            carry_and.map(|input| slot(p, input)).as_ref(),
+                        )
+                    }
+                    _ => {}
+                }
+                read
+            }
+        };
+    }
+
+    let top = wire('z', bits);
+    if let Some(carry) = carry.filter(|c| *c != top) {
+        violation(
+            &carry,
+            "is the last carry, which has to be the top output bit",
+            Some(&top),
+        );
+    }
+    violations
+}
+
+fn swap_outputs(exprs: &[BoolExpr], swaps: &[(Var, Var)]) -> Vec<BoolExpr> {
+    exprs
+        .iter()
+        .map(|e| {
+            let mut e = e.clone();
+            for (a, b) in swaps {
+                if e.target == *a {
+                    e.target = b.clone();
+                } else if e.target == *b {
+                    e.target = a.clone();
+                }
+            }
+            e
+        })
+        .collect()
+}
+
+/// Whether the circuit adds single bits, carries across every position and overflows into the top
+/// output bit. The sums have to fit into 128 bits, so adders up to 127 bits wide can be checked.
+fn adds_correctly(circuit: &Circuit, bits: usize) -> Result<bool> {
+    ensure!(
+        bits < u128::BITS as usize,
+        "Cannot simulate a {} bit adder, at most {} bits fit",
+        bits,
+        u128::BITS - 1
+    );
+    let all_ones = (1 << bits) - 1;
+    Ok((0..bits)
+        .flat_map(|i| [(1 << i, 0), (0, 1 << i), (1 << i, 1 << i)])
+        .chain([(all_ones, 1), (all_ones, all_ones)])
+        .all(|(x, y)| circuit.add(x, y) == x + y))
+}
+
+/// Pairs up the suspicious wires with the places the bits they sit in expected them in, if
+/// swapping each pair back repairs the adder.
+fn pair_swapped_wires(exprs: &[BoolExpr], wires: &[Var]) -> Option<Vec<(Var, Var)>> {
+    let mut pairs = vec![];
+    let mut paired = HashSet::new();
+    for v in verify_adder(exprs) {
+        let Some(other) = v.in_place_of else {
+            continue;
+        };
+        if v.wire != other
+            && [&v.wire, &other]
+                .iter()
+                .all(|w| wires.contains(w) && !paired.contains(*w))
+        {
+            paired.extend([v.wire.clone(), other.clone()]);
+            pairs.push((v.wire, other));
+        }
+    }
+    if paired.len() != wires.len() {
+        return None;
+    }
+
+    let repaired = swap_outputs(exprs, &pairs);
+    let bits = adder_width(exprs);
+    // wider adders than the simulation handles have to do with the structure
+    (verify_adder(&repaired).is_empty()
+        && Circuit::compile(&repaired)
+            .is_ok_and(|circuit| adds_correctly(&circuit, bits).unwrap_or(true)))
+    .then_some(pairs)
+}
+
+/// The wires whose gates were swapped, found from the structure of the adder alone.
+fn find_swapped_wires(exprs: &[BoolExpr], swaps: usize) -> Result<Vec<Var>> {
+    let violations = verify_adder(exprs);
+    for v in &violations {
+        debug!("{} {}", v.wire.name, v.reason);
+    }
+
+    let wires: Vec<_> = violations
+        .into_iter()
+        .map(|v| v.wire)
+        .unique()
+        .sorted_by(|a, b| a.name.cmp(&b.name))
+        .collect();
+    ensure!(
+        wires.len() == 2 * swaps,
+        "Found {} misplaced wires instead of {}: {}",
+        wires.len(),
+        2 * swaps,
+        wires.iter().map(|w| &w.name).join(",")
+    );
+
+    // the pairs only go to the log
+    if wires.len() >= 2 && log_enabled(Verbosity::Debug) {
+        match pair_swapped_wires(exprs, &wires) {
+            Some(pairs) => {
+                for (a, b) in pairs {
+                    debug!("swapped {} <-> {}", a.name, b.name);
+                }
+            }
+            None => debug!("No pairing of the wires repairs the adder"),
+        }
+    }
+    Ok(wires)
+}
+
+fn part2<R: BufRead>(reader: R, swaps: usize) -> Result<String> {
+    let (exprs, _) = timing::parse(|| parse_input(reader))?;
+
+    let wires = find_swapped_wires(&exprs, swaps)?;
+
+    Ok(wires.into_iter().map(|w| w.name).join(","))
+}
+
+const EXPORTS: &[Export] = &[
+    Export {
+        name: "dot",
+        reads_input: true,
+        help: "Graphviz graph of the circuit with the suspicious wires highlighted",
+    },
+    Export {
+        name: "verilog",
+        reads_input: true,
+        help: "Structural Verilog netlist of the circuit",
+    },
+];
+
+/// The circuit as a Graphviz graph. Every wire is a node: the x/y inputs, or the gate driving it,
+/// shaped by its operation. Gates on output bits have a double border, gates that
+/// [`verify_adder`] suspects are red and say why in their tooltip.
+fn to_dot(exprs: &[BoolExpr]) -> String {
+    let violations = verify_adder(exprs);
+    let mut dot = String::from("digraph circuit {\n    rankdir=LR;\n    node [style=filled];\n");
+
+    let driven: HashSet<_> = exprs.iter().map(|e| &e.target).collect();
+    let undriven = exprs
+        .iter()
+        .flat_map(|e| [&e.left, &e.right])
+        .filter(|w| !driven.contains(w))
+        .unique()
+        .sorted_by(|a, b| a.name.cmp(&b.name));
+    for wire in undriven {
+        dot += &format!(
+            "    {} [shape=circle, fillcolor=lightblue];\n",
+            dot_id(wire)
+        );
+    }
+
+    for e in exprs
+        .iter()
+        .sorted_by(|a, b| a.target.name.cmp(&b.target.name))
+    {
+        let (shape, color) = match e.op {
+            BinOp::AND => ("box", "gold"),
+            BinOp::OR => ("ellipse", "palegreen"),
+            BinOp::XOR => ("diamond", "plum"),
+        };
+        let mut attributes = format!(
+            "label=\"{}\\n{:?}\", shape={}, fillcolor={}",
+            dot_escape(&e.target.name),
+            e.op,
+            shape,
+            color
+        );
+        if e.target.is_output() {
+            attributes += ", peripheries=2";
+        }
+        let reasons: Vec<_> = violations
+            .iter()
+            .filter(|v| v.wire == e.target)
+            .map(|v| v.reason)
+            .collect();
+        if !reasons.is_empty() {
+            attributes += &format!(
+                ", color=red, penwidth=3, tooltip=\"{}\"",
+                reasons.join("; ")
+            );
+        }
+        dot += &format!("    {} [{}];\n", dot_id(&e.target), attributes);
+    }
+
+    for e in exprs
+        .iter()
+        .sorted_by(|a, b| a.target.name.cmp(&b.target.name))
+    {
+        for input in [&e.left, &e.right] {
+            dot += &format!("    {} -> {};\n", dot_id(input), dot_id(&e.target));
+        }
+    }
+    dot + "}\n"
+}
+
+/// The text of a wire name inside a quoted Graphviz string.
+fn dot_escape(name: &str) -> String {
+    name.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// A wire name as a quoted Graphviz node ID, so names like `1a` or `a-b` stay one node.
+fn dot_id(wire: &Var) -> String {
+    format!("\"{}\"", dot_escape(&wire.name))
+}
+
+const VERILOG_KEYWORDS: &[&str] = &[
+    "always",
+    "and",
+    "assign",
+    "automatic",
+    "begin",
+    "buf",
+    "bufif0",
+    "bufif1",
+    "case",
+    "casex",
+    "casez",
+    "cell",
+    "cmos",
+    "config",
+    "deassign",
+    "default",
+    "defparam",
+    "design",
+    "disable",
+    "edge",
+    "else",
+    "end",
+    "endcase",
+    "endconfig",
+    "endfunction",
+    "endgenerate",
+    "endmodule",
+    "endprimitive",
+    "endspecify",
+    "endtable",
+    "endtask",
+    "event",
+    "for",
+    "force",
+    "forever",
+    "fork",
+    "function",
+    "generate",
+    "genvar",
+    "highz0",
+    "highz1",
+    "if",
+    "ifnone",
+    "incdir",
+    "include",
+    "initial",
+    "inout",
+    "input",
+    "instance",
+    "integer",
+    "join",
+    "large",
+    "liblist",
+    "library",
+    "localparam",
+    "macromodule",
+    "medium",
+    "module",
+    "nand",
+    "negedge",
+    "nmos",
+    "nor",
+    "noshowcancelled",
+    "not",
+    "notif0",
+    "notif1",
+    "or",
+    "output",
+    "parameter",
+    "pmos",
+    "posedge",
+    "primitive",
+    "pull0",
+    "pull1",
+    "pulldown",
+    "pullup",
+    "pulsestyle_ondetect",
+    "pulsestyle_onevent",
+    "rcmos",
+    "real",
+    "realtime",
+    "reg",
+    "release",
+    "repeat",
+    "rnmos",
+    "rpmos",
+    "rtran",
+    "rtranif0",
+    "rtranif1",
+    "scalared",
+    "showcancelled",
+    "signed",
+    "small",
+    "specify",
+    "specparam",
+    "strong0",
+    "strong1",
+    "supply0",
+    "supply1",
+    "table",
+    "task",
+    "time",
+    "tran",
+    "tranif0",
+    "tranif1",
+    "tri",
+    "tri0",
+    "tri1",
+    "triand",
+    "trior",
+    "trireg",
+    "unsigned",
+    "use",
+    "uwire",
+    "vectored",
+    "wait",
+    "wand",
+    "weak0",
+    "weak1",
+    "while",
+    "wire",
+    "wor",
+    "xnor",
+    "xor",
+];
+
+/// A wire name as a Verilog identifier, escaped if it is not a plain identifier or is a keyword,
+/// like the gate primitives `and` and `xor`.
+fn verilog_name(wire: &Var) -> String {
+    let plain = wire
+        .name
+        .starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        && wire
+            .name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$');
+    if plain && VERILOG_KEYWORDS.binary_search(&wire.name.as_str()).is_err() {
+        wire.name.clone()
+    } else {
+        format!("\\{} ", wire.name)
+    }
+}
+
+/// The circuit as a structural Verilog module. The wires no gate drives are its inputs, the `z`
+/// wires its outputs.
+fn to_verilog(exprs: &[BoolExpr]) -> String {
+    let driven: HashSet<_> = exprs.iter().map(|e| &e.target).collect();
+
+    let inputs = exprs
+        .iter()
+        .flat_map(|e| [&e.left, &e.right])
+        .filter(|w| !driven.contains(w))
+        .unique()
+        .sorted()
+        .map(|w| format!("    input wire {}", verilog_name(w)));
+    let outputs = driven
+        .iter()
+        .filter(|w| w.is_output())
+        .sorted()
+        .map(|w| format!("    output wire {}", verilog_name(w)));
+
+    let mut verilog = format!(
+        "module circuit (\n{}\n);\n",
+        inputs.chain(outputs).join(",\n")
+    );
+    for wire in driven.iter().filter(|w| !w.is_output()).sorted() {
+        verilog += &format!("    wire {};\n", verilog_name(wire));
+    }
+    for e in exprs
+        .iter()
+        .sorted_by(|a, b| a.target.name.cmp(&b.target.name))
+    {
+        let op = match e.op {
+            BinOp::AND => "&",
+            BinOp::OR => "|",
+            BinOp::XOR => "^",
+        };
+        verilog += &format!(
+            "    assign {} = {} {} {};\n",
+            verilog_name(&e.target),
+            verilog_name(&e.left),
+            op,
+            verilog_name(&e.right)
+        );
+    }
+    verilog + "endmodule\n"
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    fn day(&self) -> &'static str {
+        DAY
+    }
+
+    fn params(&self) -> &'static [Param] {
+        PARAMS
+    }
+
+    fn part1(&self, reader: &mut dyn BufRead, _params: &Params) -> Result<String> {
+        Ok(part1(reader)?.to_string())
+    }
+
+    fn part2(&self, reader: &mut dyn BufRead, params: &Params) -> Result<String> {
+        Ok(part2(reader, params.get("swaps")?)?.to_string())
+    }
+
+    fn exports(&self) -> &'static [Export] {
+        EXPORTS
+    }
+
+    fn export(&self, reader: &mut dyn BufRead, name: &str, _params: &Params) -> Result<String> {
+        let (exprs, _) = parse_input(reader)?;
+        match name {
+            "dot" => Ok(to_dot(&exprs)),
+            "verilog" => Ok(to_verilog(&exprs)),
+            _ => bail!("Day {} has no export `{}`", DAY, name),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The gates of a correct ripple-carry adder in the input format, with `sNN` for the half sums,
+    /// `aNN`/`bNN` for the two carry parts and `cNN` for the carries.
+    fn ripple_carry_adder(bits: usize) -> String {
+        let mut gates = vec!["x00 XOR y00 -> z00".to_string()];
+        gates.push("x00 AND y00 -> c00".to_string());
+        for i in 1..bits {
+            gates.push(format!("x{i:0>2} XOR y{i:0>2} -> s{i:0>2}"));
+            gates.push(format!("x{i:0>2} AND y{i:0>2} -> a{i:0>2}"));
+            gates.push(format!("s{i:0>2} XOR c{:0>2} -> z{i:0>2}", i - 1));
+            gates.push(format!("s{i:0>2} AND c{:0>2} -> b{i:0>2}", i - 1));
+            gates.push(format!("a{i:0>2} OR b{i:0>2} -> c{i:0>2}"));
+        }
+        let last_carry = format!("c{:0>2}", bits - 1);
+        gates
+            .join("\n")
+            .replace(&last_carry, &format!("z{:0>2}", bits))
+    }
+
+    fn swapped(circuit: &str, swaps: &[(&str, &str)]) -> String {
+        circuit
+            .lines()
+            .map(|gate| {
+                let (expr, target) = gate.split_once(" -> ").unwrap();
+                let target = swaps
+                    .iter()
+                    .find_map(|&(a, b)| match target {
+                        t if t == a => Some(b),
+                        t if t == b => Some(a),
+                        _ => None,
+                    })
+                    .unwrap_or(target);
+                format!("{expr} -> {target}")
+            })
+            .join("\n")
+    }
+
+    #[test]
+    fn correct_adders_have_no_violations() -> Result<()> {
+        for bits in 2..=6 {
+            let (exprs, _) = parse_input(ripple_carry_adder(bits).as_bytes())?;
+            assert_eq!(bits, adder_width(&exprs));
+            assert_eq!(Vec::<Violation>::new(), verify_adder(&exprs));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn finds_and_pairs_swapped_wires() -> Result<()> {
+        let swaps = [("s02", "a02"), ("z05", "c05"), ("z07", "b07")];
+        let circuit = swapped(&ripple_carry_adder(8), &swaps);
+        let (exprs, _) = parse_input(circuit.as_bytes())?;
+
+        let wires = find_swapped_wires(&exprs, 3)?;
+        assert_eq!(
+            "a02,b07,c05,s02,z05,z07",
+            wires.iter().map(|w| &w.name).join(",")
+        );
+
+        let pairs: Vec<_> = pair_swapped_wires(&exprs, &wires)
+            .unwrap()
+            .into_iter()
+            .map(|(a, b)| [a.name, b.name].into_iter().sorted().join("-"))
+            .sorted()
+            .collect();
+        assert_eq!(vec!["a02-s02", "b07-z07", "c05-z05"], pairs);
+
+        assert!(find_swapped_wires(&exprs, 4).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn finds_swaps_between_wires_of_the_same_kind() -> Result<()> {
+        let adder = ripple_carry_adder(8);
+        let swaps = [
+            ("z03", "z05"),
+            ("s03", "s05"),
+            ("c02", "c04"),
+            ("a02", "a06"),
+            ("b03", "b05"),
+            ("c00", "a05"),
+            ("c03", "z08"),
+        ];
+        for (a, b) in swaps {
+            let (exprs, _) = parse_input(swapped(&adder, &[(a, b)]).as_bytes())?;
+            let wires = find_swapped_wires(&exprs, 1)?;
+            assert_eq!(
+                [a, b].into_iter().sorted().join(","),
+                wires.iter().map(|w| &w.name).join(",")
+            );
+            assert_eq!(1, pair_swapped_wires(&exprs, &wires).unwrap().len());
+        }
+
+        let swaps = [
+            ("z01", "z06"),
+            ("s02", "s04"),
+            ("c03", "c05"),
+            ("b07", "a01"),
+        ];
+        let (exprs, _) = parse_input(swapped(&adder, &swaps).as_bytes())?;
+        let wires = find_swapped_wires(&exprs, 4)?;
+        assert_eq!(
+            "a01,b07,c03,c05,s02,s04,z01,z06",
+            wires.iter().map(|w| &w.name).join(",")
+        );
+        let pairs: Vec<_> = pair_swapped_wires(&exprs, &wires)
+            .unwrap()
+            .into_iter()
+            .map(|(a, b)| [a.name, b.name].into_iter().sorted().join("-"))
+            .sorted()
+            .collect();
+        assert_eq!(vec!["a01-b07", "c03-c05", "s02-s04", "z01-z06"], pairs);
+        Ok(())
+    }
+
+    #[test]
+    fn compiled_adders_add() -> Result<()> {
+        let (exprs, _) = parse_input(ripple_carry_adder(6).as_bytes())?;
+        let circuit = Circuit::compile(&exprs)?;
+        for x in 0..64 {
+            for y in 0..64 {
+                assert_eq!(x + y, circuit.add(x, y));
+            }
+        }
+
+        let circuit = swapped(&ripple_carry_adder(6), &[("z03", "a03")]);
+        let (exprs, _) = parse_input(circuit.as_bytes())?;
+        assert!(!adds_correctly(&Circuit::compile(&exprs)?, 6)?);
+
+        for bits in [64, 100, 127] {
+            let (exprs, _) = parse_input(ripple_carry_adder(bits).as_bytes())?;
+            let circuit = Circuit::compile(&exprs)?;
+            assert!(adds_correctly(&circuit, bits)?);
+            let circuit = swapped(&ripple_carry_adder(bits), &[("z40", "z41")]);
+            let (exprs, _) = parse_input(circuit.as_bytes())?;
+            assert!(!adds_correctly(&Circuit::compile(&exprs)?, bits)?);
+        }
+        let (exprs, _) = parse_input(ripple_carry_adder(128).as_bytes())?;
+        assert!(adds_correctly(&Circuit::compile(&exprs)?, 128).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn broken_networks_are_reported() -> Result<()> {
+        let error = |input: &str| -> String {
+            let (exprs, values) = parse_input(input.as_bytes()).unwrap();
+            Circuit::compile(&exprs)
+                .and_then(|circuit| circuit.evaluate(&values))
+                .unwrap_err()
+                .to_string()
+        };
+        assert_eq!(
+            "The gates form a cycle through the wires abc, def",
+            error("x00: 1\nx00 AND def -> abc\nabc OR x00 -> def\nabc XOR def -> z00\n")
+        );
+        assert_eq!(
+            "Wire `y00` is neither driven by a gate nor given a value",
+            error("x00: 1\nx00 AND y00 -> z00\n")
+        );
+        assert_eq!(
+            "Wire `z00` is driven by more than one gate",
+            error("x00: 1\nx00 AND x00 -> z00\nx00 OR x00 -> z00\n")
+        );
+        assert_eq!(
+            "Wire `z00` is driven by a gate and also given a value",
+            error("x00: 1\nz00: 0\nx00 AND x00 -> z00\n")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn exports_dot_and_verilog() -> Result<()> {
+        let (exprs, _) = parse_input(ripple_carry_adder(2).as_bytes())?;
+        assert_eq!(
+            "\
+module circuit (
+    input wire x00,
+    input wire x01,
+    input wire y00,
+    input wire y01,
+    output wire z00,
+    output wire z01,
+    output wire z02
+);
+    wire a01;
+    wire b01;
+    wire c00;
+    wire s01;
+    assign a01 = x01 & y01;
+    assign b01 = c00 & s01;
+    assign c00 = x00 & y00;
+    assign s01 = x01 ^ y01;
+    assign z00 = x00 ^ y00;
+    assign z01 = c00 ^ s01;
+    assign z02 = a01 | b01;
+endmodule
+",
+            to_verilog(&exprs)
+        );
+        assert!(!to_dot(&exprs).contains("color=red"));
+
+        let circuit = swapped(&ripple_carry_adder(2), &[("z01", "b01")]);
+        let (exprs, _) = parse_input(circuit.as_bytes())?;
+        let dot = to_dot(&exprs);
+        assert!(dot.contains("    \"x00\" [shape=circle, fillcolor=lightblue];\n"));
+        assert!(dot.contains("    \"c00\" -> \"z01\";\n"));
+        let highlighted: Vec<_> = dot
+            .lines()
+            .filter(|l| l.contains("color=red"))
+            .map(|l| l.trim().split(' ').next().unwrap())
+            .collect();
+        assert_eq!(vec!["\"b01\"", "\"z01\""], highlighted);
+
+        assert_eq!("\\1ab ", verilog_name(&Var { name: "1ab".into() }));
+        assert!(VERILOG_KEYWORDS.is_sorted());
+        let (exprs, _) = parse_input("x00 AND y00 -> and\nand XOR x00 -> xor\n".as_bytes())?;
+        let verilog = to_verilog(&exprs);
+        assert!(verilog.contains("    wire \\and ;\n"));
+        assert!(verilog.contains("    assign \\and  = x00 & y00;\n"));
+        assert!(verilog.contains("    assign \\xor  = \\and  ^ x00;\n"));
+        Ok(())
+    }
+
+    #[test]
+    fn dot_quotes_every_node() {
+        let var = |name: &str| Var { name: name.into() };
+        let exprs = vec![BoolExpr {
+            left: var("1a"),
+            right: var("a-b"),
+            target: var("a.\"b\""),
+            op: BinOp::AND,
+        }];
+        let dot = to_dot(&exprs);
+        assert!(dot.contains("    \"1a\" [shape=circle, fillcolor=lightblue];\n"));
+        assert!(dot.contains("    \"a-b\" [shape=circle, fillcolor=lightblue];\n"));
+        assert!(dot.contains("    \"a.\\\"b\\\"\" [label=\"a.\\\"b\\\"\\nAND\""));
+        assert!(dot.contains("    \"1a\" -> \"a.\\\"b\\\"\";\n"));
+        assert!(dot.contains("    \"a-b\" -> \"a.\\\"b\\\"\";\n"));
+    }
+}
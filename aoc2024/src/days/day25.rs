@@ -0,0 +1,122 @@
+use crate::{examples, lines, timing, Params, Solution};
+use anyhow::*;
+use std::io::BufRead;
+
+const DAY: &str = "25";
+
+const TEST1: &str = "\
+#####
+.####
+.####
+.####
+.#.#.
+.#...
+.....
+
+#####
+##.##
+.#.##
+...##
+...#.
+...#.
+.....
+
+.....
+#....
+#....
+#...#
+#.#.#
+#.###
+#####
+
+.....
+.....
+#.#..
+###..
+###.#
+###.#
+#####
+
+.....
+.....
+.....
+#....
+#.#..
+#.#.#
+#####
+";
+
+examples! {
+    Day25;
+    example1: TEST1 => { part1: "3" },
+}
+
+const LOCK_SIZE: usize = 7;
+
+fn parse_input<R: BufRead>(reader: R) -> Result<Vec<String>> {
+    let lines = lines(DAY, reader).collect::<Result<Vec<_>>>()?;
+
+    // Zip locks and keys both into single string
+    let mut elements = vec![];
+    for schematic in lines.split(|l| l.text.is_empty()).filter(|s| !s.is_empty()) {
+        if schematic.len() != LOCK_SIZE {
+            return Err(schematic[schematic.len() - 1].error(
+                1,
+                format!(
+                    "schematic has {} rows instead of {}",
+                    schematic.len(),
+                    LOCK_SIZE
+                ),
+            ));
+        }
+        for l in schematic {
+            if let Some(column) = l.text.find(|c| c != '#' && c != '.') {
+                return Err(l.error(column + 1, "expected `#` or `.`"));
+            }
+        }
+        elements.push(schematic.iter().map(|l| l.text.as_str()).collect());
+    }
+
+    Ok(elements)
+}
+
+fn part1<R: BufRead>(reader: R) -> Result<u64> {
+    let elements = timing::parse(|| parse_input(reader))?;
+
+    let mut result = 0;
+
+    // Now check for overlaps for each pair. Locks and locks or keys and keys
+    // will just fail during the first or last row, so no need to separate
+    for e1 in elements.iter() {
+        for e2 in elements.iter() {
+            let overlap = e1
+                .chars()
+                .zip(e2.chars())
+                .any(|(a, b)| a == '#' && b == '#');
+            if !overlap {
+                result += 1;
+            }
+        }
+    }
+
+    // we counted double as we checked each pair twice
+    result /= 2;
+
+    Ok(result)
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    fn day(&self) -> &'static str {
+        DAY
+    }
+
+    fn part1(&self, reader: &mut dyn BufRead, _params: &Params) -> Result<String> {
+        Ok(part1(reader)?.to_string())
+    }
+
+    fn has_part2(&self) -> bool {
+        false
+    }
+}
@@ -0,0 +1,47 @@
+use crate::{examples, lines, timing, Params, Solution};
+use anyhow::*;
+use std::io::BufRead;
+
+const DAY: &str = "NN";
+
+const TEST: &str = "\
+<TEST-INPUT>
+"; // TODO: Add the test input
+
+examples! {
+    DayNN;
+    // TODO: Add the expected answers for the test input, e.g. `part1: "42"`
+    example: TEST => {},
+}
+
+fn parse_input<R: BufRead>(reader: R) -> Result<Vec<String>> {
+    // TODO: Parse the puzzle input, reporting malformed parts with `line.parse` / `line.error_at`
+    lines(DAY, reader).map(|line| Ok(line?.text)).collect()
+}
+
+fn part1<R: BufRead>(reader: R) -> Result<usize> {
+    let input = timing::parse(|| parse_input(reader))?;
+    // TODO: Solve Part 1 of the puzzle
+    let answer = input.len();
+    Ok(answer)
+}
+
+// fn part2<R: BufRead>(reader: R) -> Result<usize> {
+//     Ok(0)
+// }
+
+pub struct DayNN;
+
+impl Solution for DayNN {
+    fn day(&self) -> &'static str {
+        DAY
+    }
+
+    fn part1(&self, reader: &mut dyn BufRead, _params: &Params) -> Result<String> {
+        Ok(part1(reader)?.to_string())
+    }
+
+    // fn part2(&self, reader: &mut dyn BufRead, _params: &Params) -> Result<String> {
+    //     Ok(part2(reader)?.to_string())
+    // }
+}
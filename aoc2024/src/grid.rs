@@ -0,0 +1,311 @@
+use crate::lines;
+use crate::point::{Direction, Point};
+use anyhow::*;
+use std::fmt::{Display, Formatter};
+use std::io::BufRead;
+use std::ops::{Index, IndexMut};
+
+/// A rectangular puzzle map, stored row by row.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl Grid<char> {
+    /// Reads a rectangular map of characters, each of which has to be one of `allowed`.
+    pub fn read<R: BufRead>(day: &'static str, reader: R, allowed: &str) -> Result<Self> {
+        let mut rows: Vec<Vec<char>> = vec![];
+        for line in lines(day, reader) {
+            let line = line?;
+            let row: Vec<char> = line.text.chars().collect();
+            if let Some(column) = row.iter().position(|c| !allowed.contains(*c)) {
+                return Err(line.error(
+                    column + 1,
+                    format!(
+                        "unexpected `{}`, expected one of `{}`",
+                        row[column], allowed
+                    ),
+                ));
+            }
+            if let Some(first) = rows.first() {
+                if row.len() != first.len() {
+                    return Err(line.error(
+                        row.len().min(first.len()) + 1,
+                        format!("line has {} columns instead of {}", row.len(), first.len()),
+                    ));
+                }
+            }
+            rows.push(row);
+        }
+        Grid::from_rows(rows)
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self> {
+        let width = rows.first().map_or(0, |row| row.len());
+        if let Some(row) = rows.iter().position(|row| row.len() != width) {
+            bail!(
+                "Row {} has {} columns instead of {}",
+                row + 1,
+                rows[row].len(),
+                width
+            );
+        }
+        Ok(Grid {
+            width,
+            height: rows.len(),
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        (0..self.height as i64).contains(&p.row) && (0..self.width as i64).contains(&p.col)
+    }
+
+    fn offset(&self, p: Point) -> Option<usize> {
+        self.contains(p)
+            .then(|| p.row as usize * self.width + p.col as usize)
+    }
+
+    /// The cell at `p`, or `None` outside of the grid.
+    pub fn get(&self, p: Point) -> Option<&T> {
+        self.offset(p).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        self.offset(p).map(|i| &mut self.cells[i])
+    }
+
+    /// All positions, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height * width).map(move |i| Point::from((i / width, i % width)))
+    }
+
+    /// All cells with their positions, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    /// The point next to `p` in `dir`, or `None` when that leaves the grid.
+    pub fn step(&self, p: Point, dir: Direction) -> Option<Point> {
+        Some(p.step(dir)).filter(|&n| self.contains(n))
+    }
+
+    /// The orthogonal neighbours of `p` that are inside the grid.
+    pub fn neighbours4(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        p.neighbours4().into_iter().filter(|&n| self.contains(n))
+    }
+
+    /// The neighbours of `p` including diagonals that are inside the grid.
+    pub fn neighbours8(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        p.neighbours8().into_iter().filter(|&n| self.contains(n))
+    }
+
+    /// The first position, row by row, holding `value`.
+    pub fn find(&self, value: &T) -> Option<Point>
+    where
+        T: PartialEq,
+    {
+        self.iter().find(|(_, c)| *c == value).map(|(p, _)| p)
+    }
+
+    /// All positions holding `value`, row by row.
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Point> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter(move |(_, c)| *c == value)
+            .map(|(p, _)| p)
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks() panics on a zero chunk size, which an empty grid would give
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(col).step_by(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|col| self.column(col))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    fn rebuild(&self, width: usize, height: usize, source: impl Fn(usize, usize) -> Point) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..height)
+            .flat_map(|row| (0..width).map(move |col| (row, col)))
+            .map(|(row, col)| self[source(row, col)].clone())
+            .collect();
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Mirrors the grid at its main diagonal.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rebuild(self.height, self.width, |row, col| Point::from((col, row)))
+    }
+
+    /// Rotates the grid by a quarter turn clockwise.
+    pub fn rotate_right(&self) -> Self
+    where
+        T: Clone,
+    {
+        let height = self.height;
+        self.rebuild(self.height, self.width, |row, col| {
+            Point::from((height - 1 - col, row))
+        })
+    }
+
+    /// Rotates the grid by a quarter turn counterclockwise.
+    pub fn rotate_left(&self) -> Self
+    where
+        T: Clone,
+    {
+        let width = self.width;
+        self.rebuild(self.height, self.width, |row, col| {
+            Point::from((col, width - 1 - row))
+        })
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point) -> &T {
+        self.get(p).unwrap_or_else(|| {
+            panic!(
+                "{} is outside of the {}x{} grid",
+                p, self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(p)
+            .unwrap_or_else(|| panic!("{} is outside of the {}x{} grid", p, width, height))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (i, row) in self.rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        std::fmt::Result::Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ParseError;
+
+    const MAP: &str = "\
+#.S
+..#
+";
+
+    #[test]
+    fn read_and_display() -> Result<()> {
+        let grid = Grid::read("00", MAP.as_bytes(), ".#S")?;
+        assert_eq!((3, 2), (grid.width(), grid.height()));
+        assert_eq!(Some(Point::new(0, 2)), grid.find(&'S'));
+        assert_eq!(
+            vec![Point::new(0, 0), Point::new(1, 2)],
+            grid.find_all(&'#').collect::<Vec<_>>()
+        );
+        assert_eq!(MAP.trim_end(), grid.to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn read_rejects_unexpected_and_ragged_lines() {
+        let error = Grid::read("06", ".#\n#x\n".as_bytes(), ".#").unwrap_err();
+        let error = error.downcast_ref::<ParseError>().unwrap();
+        assert_eq!((2, 2), (error.line, error.column));
+
+        let error = Grid::read("06", ".#\n#\n".as_bytes(), ".#").unwrap_err();
+        let error = error.downcast_ref::<ParseError>().unwrap();
+        assert_eq!((2, 2), (error.line, error.column));
+    }
+
+    #[test]
+    fn checked_access_and_neighbours() -> Result<()> {
+        let grid = Grid::read("00", MAP.as_bytes(), ".#S")?;
+        assert_eq!(None, grid.get(Point::new(-1, 0)));
+        assert_eq!(None, grid.get(Point::new(0, 3)));
+        assert_eq!(Some(&'#'), grid.get(Point::new(1, 2)));
+        assert_eq!(
+            vec![Point::new(0, 1), Point::new(1, 0)],
+            grid.neighbours4(Point::new(0, 0)).collect::<Vec<_>>()
+        );
+        assert_eq!(5, grid.neighbours8(Point::new(1, 1)).count());
+        Ok(())
+    }
+
+    #[test]
+    fn rows_columns_and_rotation() -> Result<()> {
+        let grid = Grid::read("00", MAP.as_bytes(), ".#S")?;
+        assert_eq!(&['.', '.', '#'], grid.row(1));
+        assert_eq!(vec![&'S', &'#'], grid.column(2).collect::<Vec<_>>());
+        assert_eq!("#.\n..\nS#", grid.transpose().to_string());
+        assert_eq!(".#\n..\n#S", grid.rotate_right().to_string());
+        assert_eq!("S#\n..\n#.", grid.rotate_left().to_string());
+        assert_eq!(grid, grid.rotate_right().rotate_left());
+        assert_eq!(grid, grid.transpose().transpose());
+        Ok(())
+    }
+}
@@ -0,0 +1,76 @@
+use anyhow::*;
+use std::fs;
+use std::io::{stdin, Read};
+use std::path::{Path, PathBuf};
+
+/// Environment variable with the directory holding the `NN.txt` puzzle inputs.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Where the puzzle input of a day is read from.
+#[derive(Debug, PartialEq)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+}
+
+impl InputSource {
+    /// An explicit `input` wins over the day's file in `input_dir`; `-` selects stdin.
+    pub fn resolve(day: &str, input: Option<&Path>, input_dir: &Path) -> Self {
+        match input {
+            Some(path) if path == Path::new("-") => InputSource::Stdin,
+            Some(path) => InputSource::File(path.to_path_buf()),
+            None => InputSource::File(input_dir.join(format!("{}.txt", day))),
+        }
+    }
+
+    /// Reads the whole input, so both parts can be run on it.
+    pub fn read(&self) -> Result<Vec<u8>> {
+        let mut content = vec![];
+        match self {
+            InputSource::Stdin => {
+                stdin()
+                    .read_to_end(&mut content)
+                    .context("Cannot read input from stdin")?;
+            }
+            InputSource::File(path) => {
+                content = fs::read(path).with_context(|| {
+                    format!(
+                        "Cannot read input {} (use --input, --input-dir or {} to point elsewhere)",
+                        path.display(),
+                        INPUT_DIR_VAR
+                    )
+                })?;
+            }
+        }
+        Ok(content)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolve_prefers_explicit_input() {
+        let dir = Path::new("/inputs");
+        assert_eq!(
+            InputSource::File(PathBuf::from("/inputs/07.txt")),
+            InputSource::resolve("07", None, dir)
+        );
+        assert_eq!(
+            InputSource::File(PathBuf::from("other.txt")),
+            InputSource::resolve("07", Some(Path::new("other.txt")), dir)
+        );
+        assert_eq!(
+            InputSource::Stdin,
+            InputSource::resolve("07", Some(Path::new("-")), dir)
+        );
+    }
+
+    #[test]
+    fn missing_file_names_the_path() {
+        let source = InputSource::File(PathBuf::from("/does/not/exist/07.txt"));
+        let error = source.read().unwrap_err();
+        assert!(format!("{}", error).contains("/does/not/exist/07.txt"));
+    }
+}
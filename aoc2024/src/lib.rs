@@ -1,9 +1,421 @@
+use anyhow::*;
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::io::BufRead;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+pub mod answers;
+pub mod days;
+pub mod grid;
+pub mod input;
+pub mod point;
+pub mod scaffold;
+pub mod search;
+pub mod timing;
+
 pub fn start_day(day: &str) {
-    println!("Advent of Code 2024 - Day {:0>2}", day);
+    info!("Advent of Code 2024 - Day {:0>2}", day);
+}
+
+/// How much a run prints. Answers are always printed, everything else goes through the logging
+/// macros [`info!`], [`debug!`] and [`trace!`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, clap::ValueEnum)]
+pub enum Verbosity {
+    /// Only the answers
+    Quiet,
+    /// Answers, timings and the progress of the run
+    Info,
+    /// Intermediate results of the solvers
+    Debug,
+    /// Everything the solvers have to say, usually per step
+    Trace,
+}
+
+static VERBOSITY: AtomicU8 = AtomicU8::new(Verbosity::Info as u8);
+
+pub fn set_verbosity(verbosity: Verbosity) {
+    VERBOSITY.store(verbosity as u8, Ordering::Relaxed);
+}
+
+pub fn log_enabled(level: Verbosity) -> bool {
+    shows(VERBOSITY.load(Ordering::Relaxed), level)
+}
+
+/// Whether a run at the stored `verbosity` prints messages of `level`.
+fn shows(verbosity: u8, level: Verbosity) -> bool {
+    level as u8 <= verbosity
+}
+
+/// Prints to stdout if the run is at least at [`Verbosity::Info`].
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)*) => {
+        if $crate::log_enabled($crate::Verbosity::Info) {
+            println!($($arg)*);
+        }
+    };
+}
+
+/// Prints to stderr if the run is at least at [`Verbosity::Debug`]. The arguments are not evaluated
+/// otherwise.
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        if $crate::log_enabled($crate::Verbosity::Debug) {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+/// Prints to stderr if the run is at [`Verbosity::Trace`]. The arguments are not evaluated
+/// otherwise.
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        if $crate::log_enabled($crate::Verbosity::Trace) {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+/// A solved puzzle day. Both parts read the raw puzzle input and return the answer
+/// formatted the way the puzzle expects it to be entered.
+pub trait Solution: Sync {
+    fn day(&self) -> &'static str;
+
+    /// Puzzle parameters that are not part of the input, e.g. the size of a field.
+    fn params(&self) -> &'static [Param] {
+        &[]
+    }
+
+    fn part1(&self, reader: &mut dyn BufRead, params: &Params) -> Result<String>;
+
+    fn part2(&self, _reader: &mut dyn BufRead, _params: &Params) -> Result<String> {
+        bail!("Day {} has no part 2", self.day())
+    }
+
+    fn has_part2(&self) -> bool {
+        true
+    }
+
+    /// Other representations of the puzzle input the day can write instead of solving it.
+    fn exports(&self) -> &'static [Export] {
+        &[]
+    }
+
+    /// Writes the puzzle input as the export `name`, one of [`Solution::exports`].
+    fn export(&self, _reader: &mut dyn BufRead, name: &str, _params: &Params) -> Result<String> {
+        bail!("Day {} has no export `{}`", self.day(), name)
+    }
+}
+
+/// An output format of a day, e.g. a graph of the input for an external viewer.
+pub struct Export {
+    pub name: &'static str,
+    /// Whether the export needs the puzzle input, rather than making something up from the params.
+    pub reads_input: bool,
+    pub help: &'static str,
+}
+
+/// A parameter of a day, with the value that applies to the real puzzle inputs.
+pub struct Param {
+    pub name: &'static str,
+    pub default: &'static str,
+    pub kind: ParamKind,
+    pub help: &'static str,
+}
+
+/// The values a parameter takes. Overrides are checked against it before a day runs, the day
+/// still interprets the value itself.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParamKind {
+    /// A non-negative integer.
+    Number,
+    /// A positive integer, like a size.
+    Positive,
+    /// Comma-separated non-negative integers, possibly none.
+    Numbers,
+    /// Any text.
+    Text,
+}
+
+impl ParamKind {
+    fn check(self, value: &str) -> Result<()> {
+        match self {
+            ParamKind::Number => {
+                value.parse::<u64>()?;
+            }
+            ParamKind::Positive => {
+                if value.parse::<u64>()? == 0 {
+                    bail!("0 is not positive");
+                }
+            }
+            ParamKind::Numbers => {
+                for number in value.split(',').map(str::trim).filter(|n| !n.is_empty()) {
+                    number
+                        .parse::<u64>()
+                        .with_context(|| format!("`{}` is not a number", number))?;
+                }
+            }
+            ParamKind::Text => {}
+        }
+        Ok(())
+    }
+}
+
+impl Display for ParamKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            ParamKind::Number => "number",
+            ParamKind::Positive => "positive number",
+            ParamKind::Numbers => "numbers",
+            ParamKind::Text => "text",
+        })
+    }
+}
+
+/// The parameter values a day is solved with: the declared defaults, with some of them replaced
+/// by an example or from the command line.
+#[derive(Clone, Debug, Default)]
+pub struct Params(BTreeMap<&'static str, String>);
+
+impl Params {
+    pub fn new(declared: &'static [Param], overrides: &[(&str, &str)]) -> Result<Self> {
+        let mut values: BTreeMap<_, _> = declared
+            .iter()
+            .map(|p| (p.name, p.default.to_string()))
+            .collect();
+
+        for &(name, value) in overrides {
+            let Some(param) = declared.iter().find(|p| p.name == name) else {
+                bail!(
+                    "Unknown parameter `{}` (known: {})",
+                    name,
+                    declared
+                        .iter()
+                        .map(|p| p.name)
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                );
+            };
+            param.kind.check(value).with_context(|| {
+                format!(
+                    "Invalid value `{}` for parameter `{}`, expected {}",
+                    value, name, param.kind
+                )
+            })?;
+            values.insert(param.name, value.to_string());
+        }
+
+        Ok(Params(values))
+    }
+
+    pub fn get<T>(&self, name: &str) -> Result<T>
+    where
+        T: FromStr,
+        T::Err: std::error::Error + Send + Sync + 'static,
+    {
+        let value = self
+            .0
+            .get(name)
+            .ok_or_else(|| anyhow!("Parameter `{}` is not declared", name))?;
+        value
+            .parse()
+            .with_context(|| format!("Invalid value `{}` for parameter `{}`", value, name))
+    }
+}
+
+/// Parses a `name=value` parameter override.
+pub fn parse_param(param: &str) -> Result<(String, String)> {
+    let (name, value) = param
+        .split_once('=')
+        .ok_or_else(|| anyhow!("Expected NAME=VALUE, got `{}`", param))?;
+    Ok((name.trim().to_string(), value.trim().to_string()))
+}
+
+/// An example from the puzzle description and the answers it is expected to produce.
+pub struct Example {
+    pub name: &'static str,
+    pub input: &'static str,
+    pub params: &'static [(&'static str, &'static str)],
+    pub part1: Option<&'static str>,
+    pub part2: Option<&'static str>,
+}
+
+/// Declares the examples of a day as an `EXAMPLES` constant and generates one test per example
+/// and part, named `examples::<example>::part1` / `part2`:
+///
+/// ```text
+/// examples! {
+///     Day12;
+///     example1: TEST1 => { part1: "140", part2: "80" },
+///     example4: TEST4 => { part2: "368" },
+/// }
+/// ```
+///
+/// Examples that are smaller than the real puzzle override the day's parameters:
+///
+/// ```text
+/// examples! {
+///     Day14;
+///     example1: TEST1 => { params: { width: "11", height: "7" }, part1: "12" },
+/// }
+/// ```
+///
+/// Attributes such as `#[ignore]` can be put in front of a part.
+#[macro_export]
+macro_rules! examples {
+    (
+        $solution:expr;
+        $($name:ident: $input:expr => {
+            $(params: { $($param:ident: $value:literal),* $(,)? },)?
+            $($(#[$part1_meta:meta])* part1: $part1:literal)? $(,)?
+            $($(#[$part2_meta:meta])* part2: $part2:literal)? $(,)?
+        }),* $(,)?
+    ) => {
+        pub const EXAMPLES: &[$crate::Example] = &[$(
+            $crate::Example {
+                name: stringify!($name),
+                input: $input,
+                params: &[$($((stringify!($param), $value)),*)?],
+                part1: $crate::examples!(@answer $($part1)?),
+                part2: $crate::examples!(@answer $($part2)?),
+            },
+        )*];
+
+        #[cfg(test)]
+        mod examples {
+            $(
+                // an example without answers yet (as new days start) has no tests using these
+                #[allow(unused_imports)]
+                mod $name {
+                    use super::super::*;
+                    use $crate::Solution;
+
+                    #[allow(dead_code)]
+                    const PARAMS: &[(&str, &str)] = &[$($((stringify!($param), $value)),*)?];
+                    $(
+                        #[test]
+                        $(#[$part1_meta])*
+                        fn part1() -> ::anyhow::Result<()> {
+                            let params = $crate::Params::new($solution.params(), PARAMS)?;
+                            assert_eq!($part1, $solution.part1(&mut $input.as_bytes(), &params)?);
+                            Ok(())
+                        }
+                    )?
+                    $(
+                        #[test]
+                        $(#[$part2_meta])*
+                        fn part2() -> ::anyhow::Result<()> {
+                            let params = $crate::Params::new($solution.params(), PARAMS)?;
+                            assert_eq!($part2, $solution.part2(&mut $input.as_bytes(), &params)?);
+                            Ok(())
+                        }
+                    )?
+                }
+            )*
+        }
+    };
+    (@answer) => {
+        None
+    };
+    (@answer $answer:literal) => {
+        Some($answer)
+    };
 }
 
 // Additional common functions
 
+/// A malformed puzzle input, pointing at the offending place. Lines and columns count from 1.
+#[derive(Debug, PartialEq)]
+pub struct ParseError {
+    pub day: &'static str,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Day {} input, line {}, column {}: {}",
+            self.day, self.line, self.column, self.message
+        )?;
+        writeln!(f, "  {}", self.text)?;
+        write!(f, "  {}^", " ".repeat(self.column - 1))
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// A line of a puzzle input that knows where it is, so parsers can report malformed parts of it.
+pub struct Line {
+    pub day: &'static str,
+    pub number: usize,
+    pub text: String,
+}
+
+/// Reads the lines of a day's input, numbered from 1.
+pub fn lines<R: BufRead>(day: &'static str, reader: R) -> impl Iterator<Item = Result<Line>> {
+    reader.lines().enumerate().map(move |(i, text)| {
+        Ok(Line {
+            day,
+            number: i + 1,
+            text: text?,
+        })
+    })
+}
+
+impl Line {
+    /// An error at the given column.
+    pub fn error(&self, column: usize, message: impl Display) -> Error {
+        Error::new(ParseError {
+            day: self.day,
+            line: self.number,
+            column,
+            text: self.text.clone(),
+            message: message.to_string(),
+        })
+    }
+
+    /// An error at `part`, which is a slice of this line's text. Other strings point at the line
+    /// start.
+    pub fn error_at(&self, part: &str, message: impl Display) -> Error {
+        let start = self.text.as_ptr() as usize;
+        let offset = (part.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|&offset| offset <= self.text.len())
+            .unwrap_or(0);
+        self.error(self.text[..offset].chars().count() + 1, message)
+    }
+
+    /// An error right after the end of the line, for input that is missing.
+    pub fn error_at_end(&self, message: impl Display) -> Error {
+        self.error(self.text.chars().count() + 1, message)
+    }
+
+    /// Parses `part`, a slice of this line's text.
+    pub fn parse<T>(&self, part: &str) -> Result<T>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        part.trim()
+            .parse()
+            .map_err(|e| self.error_at(part, format!("cannot parse `{}`: {}", part.trim(), e)))
+    }
+
+    /// Splits the line at the first `delimiter`.
+    pub fn split_once(&self, delimiter: &str) -> Result<(&str, &str)> {
+        self.text
+            .split_once(delimiter)
+            .ok_or_else(|| self.error(1, format!("expected `{}`", delimiter)))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -12,4 +424,79 @@ mod tests {
     fn it_works() {
         start_day("00");
     }
+
+    #[test]
+    fn verbosity_levels_are_ordered() {
+        let info = Verbosity::Info as u8;
+        assert!(shows(info, Verbosity::Quiet));
+        assert!(shows(info, Verbosity::Info));
+        assert!(!shows(info, Verbosity::Debug));
+        assert!(!shows(info, Verbosity::Trace));
+        assert!(shows(Verbosity::Trace as u8, Verbosity::Debug));
+        assert!(!shows(Verbosity::Quiet as u8, Verbosity::Info));
+        assert!(Verbosity::Quiet < Verbosity::Info && Verbosity::Debug < Verbosity::Trace);
+    }
+
+    const PARAMS: &[Param] = &[
+        Param {
+            name: "width",
+            default: "101",
+            kind: ParamKind::Number,
+            help: "",
+        },
+        Param {
+            name: "height",
+            default: "103",
+            kind: ParamKind::Number,
+            help: "",
+        },
+    ];
+
+    #[test]
+    fn params_override_defaults() -> Result<()> {
+        let params = Params::new(PARAMS, &[("height", "7")])?;
+        assert_eq!(101, params.get::<i64>("width")?);
+        assert_eq!(7, params.get::<i64>("height")?);
+        Ok(())
+    }
+
+    #[test]
+    fn parse_error_points_at_the_part() {
+        let line = Line {
+            day: "07",
+            number: 3,
+            text: "190: 10 x9".to_string(),
+        };
+        let error = line.parse::<u64>(&line.text[8..]).unwrap_err();
+        let error = error.downcast_ref::<ParseError>().unwrap();
+        assert_eq!((3, 9), (error.line, error.column));
+        assert_eq!(
+            "Day 07 input, line 3, column 9: cannot parse `x9`: invalid digit found in string\n  \
+             190: 10 x9\n          ^",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn params_reject_unknown_and_invalid() {
+        assert!(Params::new(PARAMS, &[("depth", "3")]).is_err());
+        let error = Params::new(PARAMS, &[("width", "wide")]).unwrap_err();
+        assert_eq!(
+            "Invalid value `wide` for parameter `width`, expected number",
+            error.to_string()
+        );
+        assert!(Params::new(PARAMS, &[("width", "-3")]).is_err());
+    }
+
+    #[test]
+    fn param_kinds_check_values() {
+        assert!(ParamKind::Number.check("42").is_ok());
+        assert!(ParamKind::Number.check("").is_err());
+        assert!(ParamKind::Positive.check("1").is_ok());
+        assert!(ParamKind::Positive.check("0").is_err());
+        assert!(ParamKind::Numbers.check("").is_ok());
+        assert!(ParamKind::Numbers.check("2, 3,5").is_ok());
+        assert!(ParamKind::Numbers.check("2,x").is_err());
+        assert!(ParamKind::Text.check("+,*").is_ok());
+    }
 }
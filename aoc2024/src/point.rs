@@ -0,0 +1,225 @@
+use anyhow::*;
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A position on a puzzle map. Rows grow downwards, columns to the right. Coordinates are signed,
+/// so positions next to the map can be represented and checked against its bounds.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point {
+    pub row: i64,
+    pub col: i64,
+}
+
+impl Point {
+    pub const fn new(row: i64, col: i64) -> Self {
+        Point { row, col }
+    }
+
+    /// The adjacent point in `dir`.
+    pub fn step(self, dir: Direction) -> Point {
+        self + dir.offset()
+    }
+
+    pub fn manhattan_distance(self, other: Point) -> i64 {
+        (self.row - other.row).abs() + (self.col - other.col).abs()
+    }
+
+    /// The four orthogonally adjacent points, clockwise starting upwards.
+    pub fn neighbours4(self) -> [Point; 4] {
+        Direction::ALL.map(|dir| self.step(dir))
+    }
+
+    /// The eight adjacent points including diagonals, clockwise starting upwards.
+    pub fn neighbours8(self) -> [Point; 8] {
+        Direction::ALL
+            .map(|dir| [self.step(dir), self.step(dir).step(dir.turn_right())])
+            .as_flattened()
+            .try_into()
+            .unwrap()
+    }
+}
+
+impl From<(usize, usize)> for Point {
+    fn from((row, col): (usize, usize)) -> Self {
+        Point::new(row as i64, col as i64)
+    }
+}
+
+impl Display for Point {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.row, self.col)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.row + other.row, self.col + other.col)
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.row - other.row, self.col - other.col)
+    }
+}
+
+impl Mul<i64> for Point {
+    type Output = Point;
+
+    fn mul(self, factor: i64) -> Point {
+        Point::new(self.row * factor, self.col * factor)
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.row, -self.col)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Point) {
+        *self = *self - other;
+    }
+}
+
+/// One of the four directions on a map, up being north.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// All directions, clockwise starting upwards.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// The step of one field in this direction.
+    pub fn offset(self) -> Point {
+        match self {
+            Direction::Up => Point::new(-1, 0),
+            Direction::Right => Point::new(0, 1),
+            Direction::Down => Point::new(1, 0),
+            Direction::Left => Point::new(0, -1),
+        }
+    }
+
+    pub fn turn_right(self) -> Direction {
+        Direction::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Direction {
+        Direction::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Direction {
+        Direction::ALL[(self as usize + 2) % 4]
+    }
+
+    /// Whether this direction moves along a row.
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Direction::Left | Direction::Right)
+    }
+
+    /// The arrow puzzles use for this direction.
+    pub fn arrow(self) -> char {
+        match self {
+            Direction::Up => '^',
+            Direction::Right => '>',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+        }
+    }
+}
+
+impl TryFrom<char> for Direction {
+    type Error = Error;
+
+    /// Reads arrows (`^>v<`), compass points (`NESW`) and `UDLR`.
+    fn try_from(c: char) -> Result<Self> {
+        match c {
+            '^' | 'N' | 'U' => Ok(Direction::Up),
+            '>' | 'E' | 'R' => Ok(Direction::Right),
+            'v' | 'S' | 'D' => Ok(Direction::Down),
+            '<' | 'W' | 'L' => Ok(Direction::Left),
+            _ => Err(anyhow!("`{}` is not a direction", c)),
+        }
+    }
+}
+
+impl Display for Direction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.arrow())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turning() {
+        for dir in Direction::ALL {
+            assert_eq!(dir, dir.turn_left().turn_right());
+            assert_eq!(dir.reverse(), dir.turn_right().turn_right());
+            assert_eq!(Point::default(), dir.offset() + dir.reverse().offset());
+        }
+        assert_eq!(Direction::Left, Direction::Up.turn_left());
+        assert_eq!(Direction::Right, Direction::Up.turn_right());
+    }
+
+    #[test]
+    fn parsing() -> Result<()> {
+        for (chars, dir) in ["^NU", ">ER", "vSD", "<WL"].iter().zip(Direction::ALL) {
+            for c in chars.chars() {
+                assert_eq!(dir, Direction::try_from(c)?);
+            }
+            assert_eq!(dir, Direction::try_from(dir.arrow())?);
+        }
+        assert!(Direction::try_from('x').is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn arithmetic_and_neighbours() {
+        let p = Point::new(2, 3);
+        assert_eq!(Point::new(1, 3), p.step(Direction::Up));
+        assert_eq!(Point::new(2, 6), p + Direction::Right.offset() * 3);
+        assert_eq!(Point::new(1, 1), p - Point::new(1, 2));
+        assert_eq!(Point::new(-2, -3), -p);
+        assert_eq!(5, p.manhattan_distance(Point::default()));
+        assert_eq!(
+            [
+                (1, 3),
+                (1, 4),
+                (2, 4),
+                (3, 4),
+                (3, 3),
+                (3, 2),
+                (2, 2),
+                (1, 2)
+            ]
+            .map(|(row, col)| Point::new(row, col)),
+            p.neighbours8()
+        );
+    }
+}
@@ -0,0 +1,108 @@
+use anyhow::*;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const TEMPLATE: &str = "src/days/dayNN.rs";
+const REGISTRY: &str = "src/days/mod.rs";
+
+/// Creates `src/days/dayNN.rs` from the template, an empty `input/NN.txt`, and registers the new
+/// day in `days::ALL`. Returns the files that were created or changed.
+pub fn new_day(crate_root: &Path, day: u8) -> Result<Vec<PathBuf>> {
+    if !(1..=25).contains(&day) {
+        bail!("Day {} is not an Advent of Code day", day);
+    }
+    let day = format!("{:0>2}", day);
+
+    let source_path = crate_root.join(format!("src/days/day{}.rs", day));
+    if source_path.exists() {
+        bail!("Day {} already exists at {}", day, source_path.display());
+    }
+    let input_path = crate_root.join(format!("input/{}.txt", day));
+
+    let registry_path = crate_root.join(REGISTRY);
+    let registry = register(&fs::read_to_string(&registry_path)?, &day)?;
+    let template = fs::read_to_string(crate_root.join(TEMPLATE))?;
+
+    fs::write(&source_path, template.replace("NN", &day))?;
+    fs::write(&registry_path, registry)?;
+    let mut changed = vec![source_path, registry_path];
+
+    if !input_path.exists() {
+        fs::create_dir_all(input_path.parent().unwrap())?;
+        fs::write(&input_path, "")?;
+        changed.push(input_path);
+    }
+
+    Ok(changed)
+}
+
+/// Adds `pub mod dayNN;` and its entry in `ALL` to the registry source, keeping both sorted.
+fn register(registry: &str, day: &str) -> Result<String> {
+    let module = format!("pub mod day{};", day);
+    let entry = format!("    &day{}::Day{},", day, day);
+
+    if registry.lines().any(|l| l == module) {
+        bail!("Day {} is already registered", day);
+    }
+
+    let mut lines: Vec<String> = registry.lines().map(String::from).collect();
+    insert_sorted(&mut lines, module, |l| l.starts_with("pub mod day"))?;
+    insert_sorted(&mut lines, entry, |l| l.starts_with("    &day"))?;
+
+    Ok(lines.join("\n") + "\n")
+}
+
+fn insert_sorted(lines: &mut Vec<String>, line: String, in_block: fn(&str) -> bool) -> Result<()> {
+    let first = lines
+        .iter()
+        .position(|l| in_block(l))
+        .ok_or_else(|| anyhow!("Cannot find where to register `{}`", line.trim()))?;
+    let end = first + lines[first..].iter().take_while(|l| in_block(l)).count();
+    let position = first + lines[first..end].partition_point(|l| *l < line);
+    lines.insert(position, line);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REGISTRY: &str = "\
+pub mod day01;
+pub mod day03;
+
+use crate::Solution;
+
+pub const ALL: &[&dyn Solution] = &[
+    &day01::Day01,
+    &day03::Day03,
+];
+";
+
+    #[test]
+    fn register_keeps_days_sorted() -> Result<()> {
+        let registry = register(REGISTRY, "02")?;
+        assert_eq!(
+            "\
+pub mod day01;
+pub mod day02;
+pub mod day03;
+
+use crate::Solution;
+
+pub const ALL: &[&dyn Solution] = &[
+    &day01::Day01,
+    &day02::Day02,
+    &day03::Day03,
+];
+",
+            registry
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn register_refuses_existing_day() {
+        assert!(register(REGISTRY, "03").is_err());
+    }
+}
@@ -0,0 +1,245 @@
+use priority_queue::PriorityQueue;
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+/// The result of a shortest-path search: the distance of every reached state and, for each of
+/// them, all predecessors on an optimal path. The predecessors form a DAG that holds every
+/// optimal path from the start states.
+#[derive(Clone, Debug)]
+pub struct Paths<S> {
+    distances: HashMap<S, u64>,
+    predecessors: HashMap<S, Vec<S>>,
+    goals: Vec<S>,
+}
+
+impl<S: Clone + Eq + Hash> Paths<S> {
+    fn new() -> Self {
+        Paths {
+            distances: HashMap::new(),
+            predecessors: HashMap::new(),
+            goals: vec![],
+        }
+    }
+
+    /// Records `state` as reached at `distance` from `from`. Returns whether `state` got closer.
+    fn relax(&mut self, from: &S, state: S, distance: u64) -> bool {
+        match self.distances.get(&state) {
+            Some(&known) if known < distance => false,
+            Some(&known) if known == distance => {
+                let predecessors = self.predecessors.entry(state).or_default();
+                if !predecessors.contains(from) {
+                    predecessors.push(from.clone());
+                }
+                false
+            }
+            _ => {
+                self.distances.insert(state.clone(), distance);
+                self.predecessors.insert(state, vec![from.clone()]);
+                true
+            }
+        }
+    }
+
+    /// The distance of `state` from the closest start, `None` if it was not reached.
+    pub fn distance(&self, state: &S) -> Option<u64> {
+        self.distances.get(state).copied()
+    }
+
+    pub fn distances(&self) -> &HashMap<S, u64> {
+        &self.distances
+    }
+
+    /// The states right before `state` on its optimal paths. Empty for the start states.
+    pub fn predecessors(&self, state: &S) -> &[S] {
+        self.predecessors.get(state).map_or(&[], |p| p.as_slice())
+    }
+
+    /// The goal states reached at the lowest cost, for searches that were given a goal.
+    pub fn goals(&self) -> &[S] {
+        &self.goals
+    }
+
+    /// One optimal path from a start to `state`, both included.
+    pub fn path_to(&self, state: &S) -> Option<Vec<S>> {
+        self.distances.get(state)?;
+        let mut path = vec![state.clone()];
+        while let Some(previous) = self.predecessors(path.last().unwrap()).first() {
+            path.push(previous.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Every optimal path from a start to `state`. Their number can grow exponentially, prefer
+    /// [`Paths::on_optimal_paths`] when only the visited states matter.
+    pub fn all_paths_to(&self, state: &S) -> Vec<Vec<S>> {
+        if !self.distances.contains_key(state) {
+            return vec![];
+        }
+        let predecessors = self.predecessors(state);
+        if predecessors.is_empty() {
+            return vec![vec![state.clone()]];
+        }
+        predecessors
+            .iter()
+            .flat_map(|p| self.all_paths_to(p))
+            .map(|mut path| {
+                path.push(state.clone());
+                path
+            })
+            .collect()
+    }
+
+    /// All states that lie on an optimal path to one of `targets`, the targets included.
+    pub fn on_optimal_paths(&self, targets: impl IntoIterator<Item = S>) -> HashSet<S> {
+        let mut seen = HashSet::new();
+        let mut pending: Vec<S> = targets
+            .into_iter()
+            .filter(|t| self.distances.contains_key(t))
+            .collect();
+        while let Some(state) = pending.pop() {
+            if seen.insert(state.clone()) {
+                pending.extend(self.predecessors(&state).iter().cloned());
+            }
+        }
+        seen
+    }
+}
+
+/// Breadth-first search where every move costs 1. Explores everything reachable from `starts`.
+pub fn bfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbours: impl FnMut(&S) -> I,
+) -> Paths<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut paths = Paths::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if paths.distances.insert(start.clone(), 0).is_none() {
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(state) = queue.pop_front() {
+        let distance = paths.distances[&state] + 1;
+        for next in neighbours(&state) {
+            if paths.relax(&state, next.clone(), distance) {
+                queue.push_back(next);
+            }
+        }
+    }
+    paths
+}
+
+/// Dijkstra's search over moves with non-negative costs. Explores everything reachable from
+/// `starts`.
+pub fn dijkstra<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    neighbours: impl FnMut(&S) -> I,
+) -> Paths<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    astar(starts, neighbours, |_| 0, |_| false)
+}
+
+/// A* search towards the states satisfying `is_goal`. `heuristic` estimates the remaining cost
+/// and must never overestimate it, and not drop by more than a move costs, for the distances to be
+/// exact. The search stops once no cheaper or equally cheap way to a goal is left, so the
+/// predecessors hold every optimal path to the [`Paths::goals`].
+pub fn astar<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> u64,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Paths<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    let mut paths = Paths::new();
+    let mut queue = PriorityQueue::new();
+    for start in starts {
+        if paths.distances.insert(start.clone(), 0).is_none() {
+            let estimate = heuristic(&start);
+            queue.push(start, Reverse(estimate));
+        }
+    }
+
+    let mut best = None;
+    while let Some((state, Reverse(estimate))) = queue.pop() {
+        if best.is_some_and(|best| estimate > best) {
+            break;
+        }
+        let distance = paths.distances[&state];
+        if is_goal(&state) {
+            best = Some(distance);
+            paths.goals.push(state);
+            continue;
+        }
+        for (next, cost) in neighbours(&state) {
+            if paths.relax(&state, next.clone(), distance + cost) {
+                let estimate = distance + cost + heuristic(&next);
+                queue.push_increase(next, Reverse(estimate));
+            }
+        }
+    }
+    paths
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::Grid;
+    use crate::point::Point;
+
+    const MAZE: &str = "\
+S..#
+.#..
+...E
+";
+
+    fn open_neighbours(grid: &Grid<char>, p: Point) -> Vec<Point> {
+        grid.neighbours4(p).filter(|&n| grid[n] != '#').collect()
+    }
+
+    #[test]
+    fn bfs_distances_and_all_optimal_paths() {
+        let grid = Grid::read("00", MAZE.as_bytes(), "#.SE").unwrap();
+        let (start, end) = (grid.find(&'S').unwrap(), grid.find(&'E').unwrap());
+        let paths = bfs([start], |&p| open_neighbours(&grid, p));
+
+        assert_eq!(Some(5), paths.distance(&end));
+        assert_eq!(None, paths.distance(&Point::new(0, 3)));
+        assert_eq!(Some(start), paths.path_to(&end).unwrap().first().copied());
+        assert_eq!(6, paths.path_to(&end).unwrap().len());
+        assert_eq!(3, paths.all_paths_to(&end).len());
+        // everything but the walls lies on one of the three routes
+        assert_eq!(10, paths.on_optimal_paths([end]).len());
+    }
+
+    #[test]
+    fn dijkstra_and_astar_agree() {
+        // moving right costs 1, down 10
+        let neighbours = |&(r, c): &(u64, u64)| {
+            [(r, c + 1, 1), (r + 1, c, 10)]
+                .into_iter()
+                .filter(|&(r, c, _)| r <= 3 && c <= 3)
+                .map(|(r, c, cost)| ((r, c), cost))
+        };
+        let paths = dijkstra([(0, 0)], neighbours);
+        assert_eq!(Some(33), paths.distance(&(3, 3)));
+        assert_eq!(20, paths.all_paths_to(&(3, 3)).len());
+
+        let heuristic = |&(r, c): &(u64, u64)| (3 - r) * 10 + (3 - c);
+        let paths = astar([(0, 0)], neighbours, heuristic, |&s| s == (3, 3));
+        assert_eq!(&[(3, 3)], paths.goals());
+        assert_eq!(Some(33), paths.distance(&(3, 3)));
+        assert_eq!(16, paths.on_optimal_paths([(3, 3)]).len());
+    }
+}
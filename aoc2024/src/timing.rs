@@ -0,0 +1,216 @@
+use anyhow::*;
+use serde::{Deserialize, Serialize};
+use std::cell::Cell;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// Slowdowns below this many milliseconds are noise and never reported as regressions.
+const MIN_REGRESSION_MS: f64 = 1.0;
+
+thread_local! {
+    static PARSE_TIME: Cell<Duration> = const { Cell::new(Duration::ZERO) };
+}
+
+/// Runs a day's input parser and accounts its runtime as parse time of the part being measured.
+pub fn parse<T>(parser: impl FnOnce() -> T) -> T {
+    let start = Instant::now();
+    let result = parser();
+    PARSE_TIME.with(|t| t.set(t.get() + start.elapsed()));
+    result
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Timing {
+    pub parse: Duration,
+    pub solve: Duration,
+}
+
+/// Times a part, splitting the time spent in [`parse`] from the rest of the solution.
+pub fn measure<T>(part: impl FnOnce() -> T) -> (T, Timing) {
+    PARSE_TIME.with(|t| t.set(Duration::ZERO));
+    let start = Instant::now();
+    let result = part();
+    let total = start.elapsed();
+    let parse = PARSE_TIME.with(|t| t.get());
+
+    let timing = Timing {
+        parse,
+        solve: total.saturating_sub(parse),
+    };
+    (result, timing)
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Measurement {
+    pub day: String,
+    pub part: u8,
+    pub parse_ms: f64,
+    pub solve_ms: f64,
+}
+
+impl Measurement {
+    pub fn total_ms(&self) -> f64 {
+        self.parse_ms + self.solve_ms
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Regression {
+    pub day: String,
+    pub part: u8,
+    pub baseline_ms: f64,
+    pub current_ms: f64,
+}
+
+impl Display for Regression {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "day {} part {}: {:.3} ms -> {:.3} ms ({:+.1}%)",
+            self.day,
+            self.part,
+            self.baseline_ms,
+            self.current_ms,
+            (self.current_ms / self.baseline_ms - 1.0) * 100.0
+        )
+    }
+}
+
+/// Timings of a whole run, stored as JSON to compare later runs against.
+#[derive(Default, Debug, Serialize, Deserialize)]
+pub struct Report {
+    pub measurements: Vec<Measurement>,
+}
+
+impl Report {
+    pub fn add(&mut self, day: &str, part: u8, timing: Timing) {
+        self.measurements.push(Measurement {
+            day: day.to_string(),
+            part,
+            parse_ms: timing.parse.as_secs_f64() * 1000.0,
+            solve_ms: timing.solve.as_secs_f64() * 1000.0,
+        });
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Cannot read timing report {}", path.display()))?;
+        serde_json::from_str(&content)
+            .with_context(|| format!("Invalid timing report {}", path.display()))
+    }
+
+    pub fn save_json(&self, path: &Path) -> Result<()> {
+        fs::write(path, serde_json::to_string_pretty(self)? + "\n")
+            .with_context(|| format!("Cannot write timing report {}", path.display()))
+    }
+
+    pub fn save_markdown(&self, path: &Path) -> Result<()> {
+        fs::write(path, self.to_markdown())
+            .with_context(|| format!("Cannot write timing report {}", path.display()))
+    }
+
+    pub fn to_markdown(&self) -> String {
+        let mut table = String::from(
+            "| Day | Part | Parse (ms) | Solve (ms) | Total (ms) |\n\
+             |----:|-----:|-----------:|-----------:|-----------:|\n",
+        );
+        for m in &self.measurements {
+            table += &format!(
+                "| {} | {} | {:.3} | {:.3} | {:.3} |\n",
+                m.day,
+                m.part,
+                m.parse_ms,
+                m.solve_ms,
+                m.total_ms()
+            );
+        }
+        table
+    }
+
+    /// Parts that got slower than in `baseline` by more than `threshold_percent`.
+    pub fn regressions(&self, baseline: &Report, threshold_percent: f64) -> Vec<Regression> {
+        self.measurements
+            .iter()
+            .filter_map(|m| {
+                let base = baseline
+                    .measurements
+                    .iter()
+                    .find(|b| b.day == m.day && b.part == m.part)?;
+                let (baseline_ms, current_ms) = (base.total_ms(), m.total_ms());
+
+                let slower = current_ms > baseline_ms * (1.0 + threshold_percent / 100.0)
+                    && current_ms - baseline_ms >= MIN_REGRESSION_MS;
+                slower.then(|| Regression {
+                    day: m.day.clone(),
+                    part: m.part,
+                    baseline_ms,
+                    current_ms,
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timing(parse_ms: u64, solve_ms: u64) -> Timing {
+        Timing {
+            parse: Duration::from_millis(parse_ms),
+            solve: Duration::from_millis(solve_ms),
+        }
+    }
+
+    #[test]
+    fn measure_separates_parse_time() {
+        let (result, timing) = measure(|| {
+            let input = parse(|| {
+                std::thread::sleep(Duration::from_millis(20));
+                21
+            });
+            input * 2
+        });
+        assert_eq!(42, result);
+        assert!(timing.parse >= Duration::from_millis(20));
+        assert!(timing.solve < timing.parse);
+    }
+
+    #[test]
+    fn regressions_over_threshold() {
+        let mut baseline = Report::default();
+        baseline.add("01", 1, timing(10, 90));
+        baseline.add("01", 2, timing(10, 90));
+        baseline.add("02", 1, timing(0, 0));
+
+        let mut current = Report::default();
+        current.add("01", 1, timing(10, 95));
+        current.add("01", 2, timing(10, 190));
+        current.add("02", 1, timing(0, 0));
+        current.add("03", 1, timing(0, 500));
+
+        assert_eq!(
+            vec![Regression {
+                day: "01".to_string(),
+                part: 2,
+                baseline_ms: 100.0,
+                current_ms: 200.0,
+            }],
+            current.regressions(&baseline, 10.0)
+        );
+    }
+
+    #[test]
+    fn markdown_table() {
+        let mut report = Report::default();
+        report.add("07", 2, timing(1, 41));
+        assert_eq!(
+            "| Day | Part | Parse (ms) | Solve (ms) | Total (ms) |\n\
+             |----:|-----:|-----------:|-----------:|-----------:|\n\
+             | 07 | 2 | 1.000 | 41.000 | 42.000 |\n",
+            report.to_markdown()
+        );
+    }
+}